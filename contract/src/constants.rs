@@ -10,17 +10,23 @@ pub const ARG_ACL_PACKAGE_MODE: &str = "acl_package_mode";
 pub const ARG_ACL_WHITELIST: &str = "acl_whitelist";
 pub const ARG_ADDITIONAL_REQUIRED_METADATA: &str = "additional_required_metadata";
 pub const ARG_ALLOW_MINTING: &str = "allow_minting";
+pub const ARG_ALLOWED_TOKEN_URI_SCHEMES: &str = "allowed_token_uri_schemes";
 pub const ARG_APPROVE_ALL: &str = "approve_all";
+pub const ARG_BURN_FILTER_CONTRACT: &str = "burn_filter_contract";
+pub const ARG_BLOCKLIST: &str = "blocklist";
 pub const ARG_BURN_MODE: &str = "burn_mode";
 pub const ARG_COLLECTION_NAME: &str = "collection_name";
 pub const ARG_COLLECTION_SYMBOL: &str = "collection_symbol";
 pub const ARG_CONTRACT_WHITELIST: &str = "contract_whitelist";
 pub const ARG_EVENTS_MODE: &str = "events_mode";
+pub const ARG_EXPIRES_AT: &str = "expires_at";
 pub const ARG_HASH_KEY_NAME_1_0_0: &str = "hash_key_name";
 pub const ARG_HOLDER_MODE: &str = "holder_mode";
 pub const ARG_IDENTIFIER_MODE: &str = "identifier_mode";
 pub const ARG_JSON_SCHEMA: &str = "json_schema";
 pub const ARG_METADATA_MUTABILITY: &str = "metadata_mutability";
+pub const ARG_METADATA_SIZE_LIMITS: &str = "metadata_size_limits";
+pub const ARG_MINT_FILTER_CONTRACT: &str = "mint_filter_contract";
 pub const ARG_MINTING_MODE: &str = "minting_mode";
 pub const ARG_NAMED_KEY_CONVENTION: &str = "named_key_convention";
 pub const ARG_NFT_KIND: &str = "nft_kind";
@@ -28,45 +34,83 @@ pub const ARG_NFT_METADATA_KIND: &str = "nft_metadata_kind";
 pub const ARG_NFT_PACKAGE_KEY: &str = "cep78_package_key";
 pub const ARG_OPTIONAL_METADATA: &str = "optional_metadata";
 pub const ARG_OPERATOR: &str = "operator";
+pub const ARG_OPEN_SUPPLY: &str = "open_supply";
+pub const ARG_FORCE_TRANSFER_MODE: &str = "force_transfer_mode";
+pub const ARG_OPERATOR_ALLOWLIST: &str = "operator_allowlist";
+pub const ARG_OPERATOR_ALLOWLIST_MODE: &str = "operator_allowlist_mode";
 pub const ARG_OPERATOR_BURN_MODE: &str = "operator_burn_mode";
 pub const ARG_OWNERSHIP_MODE: &str = "ownership_mode";
 pub const ARG_OWNER_LOOKUP_MODE: &str = "owner_reverse_lookup_mode";
+pub const ARG_PACKAGE_HOLDER_MODE: &str = "package_holder_mode";
 pub const ARG_PACKAGE_OPERATOR_MODE: &str = "package_operator_mode";
+pub const ARG_PENDING_TRANSFER_MODE: &str = "pending_transfer_mode";
+pub const ARG_PAGE_SIZE: &str = "page_size";
 pub const ARG_RECEIPT_NAME: &str = "receipt_name";
 pub const ARG_SOURCE_KEY: &str = "source_key";
 pub const ARG_SPENDER: &str = "spender";
 pub const ARG_TARGET_KEY: &str = "target_key";
 pub const ARG_TOKEN_HASH: &str = "token_hash";
 pub const ARG_TOKEN_ID: &str = "token_id";
+pub const ARG_TOKEN_IDS: &str = "token_ids";
 pub const ARG_TOKEN_META_DATA: &str = "token_meta_data";
 pub const ARG_TOKEN_OWNER: &str = "token_owner";
+pub const ARG_TOKEN_RANGE: &str = "token_range";
 pub const ARG_TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const ARG_TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
+pub const ARG_TRANSFER_FILTER_CONTRACTS: &str = "transfer_filter_contracts";
+pub const ARG_TRANSFER_FILTER_INTERFACE: &str = "transfer_filter_interface";
+pub const ARG_CALLER: &str = "caller";
+pub const ARG_CALLER_ROLE: &str = "caller_role";
+pub const ARG_DATA: &str = "data";
+pub const ARG_DEADLINE: &str = "deadline";
+pub const ARG_ENTRY_POINT: &str = "entry_point";
+pub const ARG_NONCE: &str = "nonce";
+pub const ARG_REASON: &str = "reason";
+pub const ARG_OWNER_PUBLIC_KEY: &str = "owner_public_key";
+pub const ARG_SIGNATURE: &str = "signature";
 pub const ARG_WHITELIST_MODE: &str = "whitelist_mode";
 
 pub const ENTRY_POINT_APPROVE: &str = "approve";
+pub const ENTRY_POINT_APPROVE_AND_CALL: &str = "approve_and_call";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
 pub const ENTRY_POINT_BURN: &str = "burn";
+pub const ENTRY_POINT_FORCE_TRANSFER: &str = "force_transfer";
+pub const ENTRY_POINT_CANCEL: &str = "cancel";
+pub const ENTRY_POINT_CANCEL_PERMIT: &str = "cancel_permit";
+pub const ENTRY_POINT_CLAIM: &str = "claim";
 pub const ENTRY_POINT_GET_APPROVED: &str = "get_approved";
+pub const ENTRY_POINT_GET_APPROVED_SPENDERS: &str = "get_approved_spenders";
 pub const ENTRY_POINT_INIT: &str = "init";
 pub const ENTRY_POINT_IS_APPROVED_FOR_ALL: &str = "is_approved_for_all";
+pub const ENTRY_POINT_IS_LOCKED: &str = "is_locked";
+pub const ENTRY_POINT_LOCK_TOKEN: &str = "lock_token";
 pub const ENTRY_POINT_METADATA: &str = "metadata";
 pub const ENTRY_POINT_MIGRATE: &str = "migrate";
 pub const ENTRY_POINT_MINT: &str = "mint";
 pub const ENTRY_POINT_OWNER_OF: &str = "owner_of";
+pub const ENTRY_POINT_PERMIT: &str = "permit";
 pub const ENTRY_POINT_REVOKE: &str = "revoke";
+pub const ENTRY_POINT_REVOKE_TOKEN: &str = "revoke_token";
 pub const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
 pub const ENTRY_POINT_SET_APPROVALL_FOR_ALL: &str = "set_approval_for_all";
+pub const ENTRY_POINT_SET_APPROVAL_FOR_TOKENS: &str = "set_approval_for_tokens";
 pub const ENTRY_POINT_SET_TOKEN_METADATA: &str = "set_token_metadata";
 pub const ENTRY_POINT_SET_VARIABLES: &str = "set_variables";
 pub const ENTRY_POINT_TRANSFER: &str = "transfer";
+pub const ENTRY_POINT_UNLOCK_TOKEN: &str = "unlock_token";
 pub const ENTRY_POINT_UPDATED_RECEIPTS: &str = "updated_receipts";
 
 pub const ACL_PACKAGE_MODE: &str = "acl_package_mode";
 pub const ACL_WHITELIST: &str = "acl_whitelist";
 pub const ALLOW_MINTING: &str = "allow_minting";
+pub const ALLOWED_TOKEN_URI_SCHEMES: &str = "allowed_token_uri_schemes";
+pub const APPROVAL_EXPIRIES: &str = "approval_expiries";
 pub const APPROVED: &str = "approved";
+pub const APPROVED_SPENDERS: &str = "approved_spenders";
+pub const BURN_FILTER_CONTRACT: &str = "burn_filter_contract";
+pub const BURN_FILTER_CONTRACT_METHOD: &str = "can_burn";
 pub const BURN_MODE: &str = "burn_mode";
+pub const BLOCKLIST: &str = "blocklist";
 pub const BURNT_TOKENS: &str = "burnt_tokens";
 pub const COLLECTION_NAME: &str = "collection_name";
 pub const COLLECTION_SYMBOL: &str = "collection_symbol";
@@ -74,18 +118,23 @@ pub const CONTRACT_WHITELIST: &str = "contract_whitelist";
 pub const EVENT_TYPE: &str = "event_type";
 pub const EVENTS: &str = "events";
 pub const EVENTS_MODE: &str = "events_mode";
+pub const FORCE_TRANSFER_MODE: &str = "force_transfer_mode";
 pub const HASH_BY_INDEX: &str = "hash_by_index";
 pub const HOLDER_MODE: &str = "holder_mode";
 pub const IDENTIFIER_MODE: &str = "identifier_mode";
 pub const INDEX_BY_HASH: &str = "index_by_hash";
 pub const INSTALLER: &str = "installer";
 pub const JSON_SCHEMA: &str = "json_schema";
+pub const LOCKED_TOKENS: &str = "locked_tokens";
 pub const METADATA_CEP78: &str = "metadata_cep78";
 pub const METADATA_CUSTOM_VALIDATED: &str = "metadata_custom_validated";
 pub const METADATA_MUTABILITY: &str = "metadata_mutability";
+pub const METADATA_SIZE_LIMITS: &str = "metadata_size_limits";
 pub const METADATA_NFT721: &str = "metadata_nft721";
 pub const METADATA_RAW: &str = "metadata_raw";
 pub const MIGRATION_FLAG: &str = "migration_flag";
+pub const MINT_FILTER_CONTRACT: &str = "mint_filter_contract";
+pub const MINT_FILTER_CONTRACT_METHOD: &str = "can_mint";
 pub const MINTING_MODE: &str = "minting_mode";
 pub const NFT_KIND: &str = "nft_kind";
 pub const NFT_METADATA_KIND: &str = "nft_metadata_kind";
@@ -93,14 +142,27 @@ pub const NFT_METADATA_KINDS: &str = "nft_metadata_kinds";
 pub const NUMBER_OF_MINTED_TOKENS: &str = "number_of_minted_tokens";
 pub const OPERATOR: &str = "operator";
 pub const OPERATORS: &str = "operators";
+pub const OPERATOR_EXPIRIES: &str = "operator_expiries";
+pub const OPEN_SUPPLY: &str = "open_supply";
+pub const OPERATOR_ALLOWLIST: &str = "operator_allowlist";
+pub const OPERATOR_ALLOWLIST_MODE: &str = "operator_allowlist_mode";
 pub const OPERATOR_BURN_MODE: &str = "operator_burn_mode";
 pub const OWNED_TOKENS: &str = "owned_tokens";
 pub const OWNER: &str = "owner";
 pub const BURNER: &str = "burner";
+pub const REVOKER: &str = "revoker";
+pub const REASON: &str = "reason";
 pub const OWNERSHIP_MODE: &str = "ownership_mode";
+pub const PACKAGE_HOLDER_MODE: &str = "package_holder_mode";
 pub const PACKAGE_OPERATOR_MODE: &str = "package_operator_mode";
 pub const PAGE_LIMIT: &str = "page_limit";
+pub const PAGE_SIZE: &str = "page_size";
 pub const PAGE_TABLE: &str = "page_table";
+pub const LEGACY_PAGE_TABLE: &str = "legacy_page_table";
+pub const PACKED_PAGES: &str = "packed_pages";
+pub const PENDING_TRANSFERS: &str = "pending_transfers";
+pub const PENDING_TRANSFER_MODE: &str = "pending_transfer_mode";
+pub const PERMIT_NONCES: &str = "permit_nonces";
 pub const RECEIPT_NAME: &str = "receipt_name";
 pub const RECIPIENT: &str = "recipient";
 pub const REPORTING_MODE: &str = "reporting_mode";
@@ -110,15 +172,36 @@ pub const SPENDER: &str = "spender";
 pub const TOKEN_COUNT: &str = "balances";
 pub const TOKEN_ID: &str = "token_id";
 pub const TOKEN_ISSUERS: &str = "token_issuers";
+pub const TOKEN_OPERATORS: &str = "token_operators";
 pub const TOKEN_OWNERS: &str = "token_owners";
+pub const TOKEN_RANGES: &str = "token_ranges";
 pub const TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
 pub const TRANSFER_FILTER_CONTRACT_METHOD: &str = "can_transfer";
+pub const TRANSFER_FILTER_CONTRACTS: &str = "transfer_filter_contracts";
+pub const TRANSFER_FILTER_INTERFACE: &str = "transfer_filter_interface";
 pub const UNMATCHED_HASH_COUNT: &str = "unmatched_hash_count";
 pub const WHITELIST_MODE: &str = "whitelist_mode";
 
 // The cap on the amount of tokens within a given CEP-78 collection.
 pub const MAX_TOTAL_TOKEN_SUPPLY: u64 = 1_000_000u64;
 
+// The bounds on the number of tokens tracked by a page of the owner reverse lookup. Smaller pages
+// multiply the page dictionaries to create, larger pages the bytes rewritten by each page write.
+pub const MIN_PAGE_SIZE: u64 = 8u64;
+pub const MAX_PAGE_SIZE: u64 = 10_000u64;
+
+// The cap on the number of page dictionaries created by a single installation or supply increase,
+// being the number of pages tracking the maximum total token supply with the default page size.
+pub const MAX_NEW_PAGES: u64 = 1_000u64;
+
+// The cap on the number of disjoint token ranges an operator can be approved for, as they are
+// stored with the approval and searched on every transfer by the operator.
+pub const MAX_TOKEN_RANGES: usize = 100usize;
+
+// The cap on the number of chained transfer filter contracts, each of them being called on every
+// transfer.
+pub const MAX_TRANSFER_FILTER_CONTRACTS: usize = 8usize;
+
 pub const ACCESS_KEY_NAME_1_0_0: &str = "nft_contract_package_access";
 pub const HASH_KEY_NAME_1_0_0: &str = "nft_contract_package";
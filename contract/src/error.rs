@@ -173,6 +173,73 @@ pub enum NFTCoreError {
     MissingOperatorBurnMode = 168,
     InvalidIdentifier = 169,
     DuplicateIdentifier = 170,
+    InvalidCEP78Checksum = 171,
+    ForbiddenTokenURIScheme = 172,
+    MissingAllowedTokenURISchemes = 173,
+    InvalidAllowedTokenURISchemes = 174,
+    MissingMetadataSizeLimits = 175,
+    InvalidMetadataSizeLimits = 176,
+    MetadataSizeLimitExceeded = 177,
+    MissingCustomTokenHash = 178,
+    CannotDecreaseTotalTokenSupply = 179,
+    MissingOpenSupply = 180,
+    InvalidOpenSupply = 181,
+    MissingPageSize = 182,
+    InvalidPageSize = 183,
+    MissingTransferFilterInterface = 184,
+    InvalidTransferFilterInterface = 185,
+    InvalidTransferData = 186,
+    InvalidMintFilterContract = 187,
+    MissingMintFilterContract = 188,
+    MintFilterContractDenied = 189,
+    InvalidBurnFilterContract = 190,
+    MissingBurnFilterContract = 191,
+    BurnFilterContractDenied = 192,
+    InvalidTransferFilterContracts = 193,
+    MissingTransferFilterContracts = 194,
+    InvalidApprovalExpiry = 195,
+    ApprovalExpiryInPast = 196,
+    InvalidTokenIds = 197,
+    InvalidTokenRange = 198,
+    MissingOwnerPublicKey = 199,
+    InvalidOwnerPublicKey = 200,
+    MissingPermitSignature = 201,
+    InvalidPermitSignature = 202,
+    MissingPermitNonce = 203,
+    InvalidPermitNonce = 204,
+    MissingPermitDeadline = 205,
+    InvalidPermitDeadline = 206,
+    PermitExpired = 207,
+    MissingOperatorAllowlistMode = 208,
+    InvalidOperatorAllowlistMode = 209,
+    MissingOperatorAllowlist = 210,
+    InvalidOperatorAllowlist = 211,
+    UnlistedOperator = 212,
+    MissingPackageHolderMode = 213,
+    InvalidPackageHolderMode = 214,
+    LockedToken = 215,
+    InvalidTokenLocker = 216,
+    InvalidTokenRevoker = 217,
+    MissingRevocationReason = 218,
+    InvalidRevocationReason = 219,
+    MissingForceTransferMode = 220,
+    InvalidForceTransferMode = 221,
+    MissingBlocklist = 222,
+    InvalidBlocklist = 223,
+    BlockedKey = 224,
+    MissingPendingTransferMode = 225,
+    InvalidPendingTransferMode = 226,
+    PendingTransfer = 227,
+    MissingPendingTransfer = 228,
+    InvalidClaimant = 229,
+    UnapprovedSpender = 230,
+    InvalidApproveAndCallSpender = 231,
+    MissingEntryPointName = 232,
+    InvalidEntryPointName = 233,
+    InvalidApproveAndCallData = 234,
+    MissingEventsSchema = 235,
+    InvalidEventsSchema = 236,
+    TooManyTransferFilterContracts = 237,
 }
 
 impl From<NFTCoreError> for ApiError {
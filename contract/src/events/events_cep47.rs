@@ -2,6 +2,7 @@ use alloc::{
     collections::BTreeMap,
     format,
     string::{String, ToString},
+    vec::Vec,
 };
 
 use casper_contract::{
@@ -12,8 +13,9 @@ use casper_types::Key;
 
 use crate::{
     constants::{
-        BURNER, EVENTS, EVENT_TYPE, OPERATOR, OWNER, PREFIX_CEP78, PREFIX_HASH_KEY_NAME, RECIPIENT,
-        SENDER, SPENDER, TOKEN_ID,
+        BURNER, EVENTS, EVENT_TYPE, OPERATOR, OWNER, PREFIX_CEP78, PREFIX_HASH_KEY_NAME, REASON,
+        RECIPIENT, REVOKER, SENDER, SPENDER, TOKEN_ID, TOKEN_RANGES, TRANSFER_FILTER_CONTRACT,
+        TRANSFER_FILTER_CONTRACTS,
     },
     error::NFTCoreError,
     modalities::TokenIdentifier,
@@ -30,6 +32,12 @@ pub enum CEP47Event {
         token_id: TokenIdentifier,
         burner: Key,
     },
+    Revoked {
+        owner: Key,
+        token_id: TokenIdentifier,
+        revoker: Key,
+        reason: u8,
+    },
     ApprovalGranted {
         owner: Key,
         spender: Key,
@@ -39,10 +47,20 @@ pub enum CEP47Event {
         owner: Key,
         token_id: TokenIdentifier,
     },
+    SpenderRevoked {
+        owner: Key,
+        spender: Key,
+        token_id: TokenIdentifier,
+    },
     ApprovalForAll {
         owner: Key,
         operator: Key,
     },
+    ApprovalForTokens {
+        owner: Key,
+        operator: Key,
+        token_ranges: Vec<(u64, u64)>,
+    },
     RevokedForAll {
         owner: Key,
         operator: Key,
@@ -52,10 +70,26 @@ pub enum CEP47Event {
         recipient: Key,
         token_id: TokenIdentifier,
     },
+    ForceTransfer {
+        owner: Key,
+        recipient: Key,
+        token_id: TokenIdentifier,
+    },
+    PendingTransfer {
+        owner: Key,
+        recipient: Key,
+        token_id: TokenIdentifier,
+    },
     MetadataUpdate {
         token_id: TokenIdentifier,
     },
     VariablesSet,
+    TransferFilterContractUpdated {
+        transfer_filter_contract: Option<Key>,
+    },
+    TransferFilterContractsUpdated {
+        transfer_filter_contracts: Vec<Key>,
+    },
     Migrate,
 }
 
@@ -97,6 +131,21 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(BURNER, burner.to_string());
             event
         }
+        CEP47Event::Revoked {
+            owner,
+            token_id,
+            revoker,
+            reason,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "Revoked".to_string());
+            event.insert(OWNER, owner.to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
+            event.insert(REVOKER, revoker.to_string());
+            event.insert(REASON, reason.to_string());
+            event
+        }
         CEP47Event::ApprovalGranted {
             owner,
             spender,
@@ -118,6 +167,19 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(TOKEN_ID, token_id.to_string());
             event
         }
+        CEP47Event::SpenderRevoked {
+            owner,
+            spender,
+            token_id,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "SpenderRevoked".to_string());
+            event.insert(OWNER, owner.to_string());
+            event.insert(SPENDER, spender.to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
+            event
+        }
         CEP47Event::ApprovalForAll { owner, operator } => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
@@ -126,6 +188,26 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(OPERATOR, operator.to_string());
             event
         }
+        CEP47Event::ApprovalForTokens {
+            owner,
+            operator,
+            token_ranges,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "ApprovalForTokens".to_string());
+            event.insert(OWNER, owner.to_string());
+            event.insert(OPERATOR, operator.to_string());
+            event.insert(
+                TOKEN_RANGES,
+                token_ranges
+                    .iter()
+                    .map(|(start, end)| format!("{start}..{end}"))
+                    .collect::<Vec<String>>()
+                    .join(","),
+            );
+            event
+        }
         CEP47Event::RevokedForAll { owner, operator } => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
@@ -147,6 +229,32 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(TOKEN_ID, token_id.to_string());
             event
         }
+        CEP47Event::ForceTransfer {
+            owner,
+            recipient,
+            token_id,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "ForceTransfer".to_string());
+            event.insert(OWNER, owner.to_string());
+            event.insert(RECIPIENT, recipient.to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
+            event
+        }
+        CEP47Event::PendingTransfer {
+            owner,
+            recipient,
+            token_id,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "PendingTransfer".to_string());
+            event.insert(OWNER, owner.to_string());
+            event.insert(RECIPIENT, recipient.to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
+            event
+        }
         CEP47Event::MetadataUpdate { token_id } => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
@@ -166,6 +274,36 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(EVENT_TYPE, "VariablesSet".to_string());
             event
         }
+        CEP47Event::TransferFilterContractUpdated {
+            transfer_filter_contract,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "TransferFilterContractUpdated".to_string());
+            if let Some(transfer_filter_contract) = transfer_filter_contract {
+                event.insert(
+                    TRANSFER_FILTER_CONTRACT,
+                    transfer_filter_contract.to_string(),
+                );
+            }
+            event
+        }
+        CEP47Event::TransferFilterContractsUpdated {
+            transfer_filter_contracts,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "TransferFilterContractsUpdated".to_string());
+            event.insert(
+                TRANSFER_FILTER_CONTRACTS,
+                transfer_filter_contracts
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(","),
+            );
+            event
+        }
     };
     let dictionary_uref = match runtime::get_key(EVENTS) {
         Some(dict_uref) => dict_uref.into_uref().unwrap_or_revert(),
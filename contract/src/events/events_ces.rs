@@ -1,4 +1,7 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use casper_event_standard::Event;
 use casper_types::Key;
@@ -39,6 +42,25 @@ impl Burn {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Revoked {
+    owner: Key,
+    token_id: String,
+    revoker: Key,
+    reason: u8,
+}
+
+impl Revoked {
+    pub fn new(owner: Key, token_id: TokenIdentifier, revoker: Key, reason: u8) -> Self {
+        Self {
+            owner,
+            token_id: token_id.to_string(),
+            revoker,
+            reason,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct Approval {
     owner: Key,
@@ -71,6 +93,23 @@ impl ApprovalRevoked {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SpenderRevoked {
+    owner: Key,
+    spender: Key,
+    token_id: String,
+}
+
+impl SpenderRevoked {
+    pub fn new(owner: Key, spender: Key, token_id: TokenIdentifier) -> Self {
+        Self {
+            owner,
+            spender,
+            token_id: token_id.to_string(),
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct ApprovalForAll {
     owner: Key,
@@ -83,6 +122,23 @@ impl ApprovalForAll {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ApprovalForTokens {
+    owner: Key,
+    operator: Key,
+    token_ranges: Vec<(u64, u64)>,
+}
+
+impl ApprovalForTokens {
+    pub fn new(owner: Key, operator: Key, token_ranges: Vec<(u64, u64)>) -> Self {
+        Self {
+            owner,
+            operator,
+            token_ranges,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct RevokedForAll {
     owner: Key,
@@ -119,6 +175,40 @@ impl Transfer {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ForceTransfer {
+    owner: Key,
+    recipient: Key,
+    token_id: String,
+}
+
+impl ForceTransfer {
+    pub fn new(owner: Key, recipient: Key, token_id: TokenIdentifier) -> Self {
+        Self {
+            owner,
+            recipient,
+            token_id: token_id.to_string(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct PendingTransfer {
+    owner: Key,
+    recipient: Key,
+    token_id: String,
+}
+
+impl PendingTransfer {
+    pub fn new(owner: Key, recipient: Key, token_id: TokenIdentifier) -> Self {
+        Self {
+            owner,
+            recipient,
+            token_id: token_id.to_string(),
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct MetadataUpdated {
     token_id: String,
@@ -143,6 +233,32 @@ impl VariablesSet {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferFilterContractUpdated {
+    transfer_filter_contract: Option<Key>,
+}
+
+impl TransferFilterContractUpdated {
+    pub fn new(transfer_filter_contract: Option<Key>) -> Self {
+        Self {
+            transfer_filter_contract,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferFilterContractsUpdated {
+    transfer_filter_contracts: Vec<Key>,
+}
+
+impl TransferFilterContractsUpdated {
+    pub fn new(transfer_filter_contracts: Vec<Key>) -> Self {
+        Self {
+            transfer_filter_contracts,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq, Default)]
 pub struct Migration {}
 
@@ -29,51 +29,69 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, runtime_args, CLType, CLValue, ContractHash, ContractPackageHash,
-    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag, Parameter, RuntimeArgs,
-    Tagged,
+    bytesrepr::Bytes, contracts::NamedKeys, runtime_args, CLType, CLTyped, CLValue, ContractHash,
+    ContractPackageHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag,
+    Parameter, PublicKey, RuntimeArgs, Tagged,
 };
 use constants::{
-    ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOW_MINTING, APPROVED,
-    ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST,
-    ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOW_MINTING, ARG_APPROVE_ALL, ARG_BURN_MODE,
-    ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST, ARG_EVENTS_MODE,
-    ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA,
-    ARG_METADATA_MUTABILITY, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
-    ARG_NFT_METADATA_KIND, ARG_NFT_PACKAGE_KEY, ARG_OPERATOR, ARG_OPERATOR_BURN_MODE,
-    ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE,
-    ARG_RECEIPT_NAME, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-    ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT,
-    ARG_WHITELIST_MODE, BURNT_TOKENS, BURN_MODE, COLLECTION_NAME, COLLECTION_SYMBOL,
-    ENTRY_POINT_APPROVE, ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BURN, ENTRY_POINT_GET_APPROVED,
-    ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_METADATA, ENTRY_POINT_MIGRATE,
-    ENTRY_POINT_MINT, ENTRY_POINT_OWNER_OF, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE,
-    ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
-    ENTRY_POINT_TRANSFER, ENTRY_POINT_UPDATED_RECEIPTS, EVENTS_MODE, HASH_BY_INDEX,
-    HASH_KEY_NAME_1_0_0, HOLDER_MODE, IDENTIFIER_MODE, INDEX_BY_HASH, INSTALLER, JSON_SCHEMA,
-    MAX_TOTAL_TOKEN_SUPPLY, METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_MUTABILITY,
-    METADATA_NFT721, METADATA_RAW, MINTING_MODE, NFT_KIND, NFT_METADATA_KIND, NFT_METADATA_KINDS,
-    NUMBER_OF_MINTED_TOKENS, OPERATOR, OPERATORS, OPERATOR_BURN_MODE, OWNED_TOKENS, OWNERSHIP_MODE,
-    PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PAGE_TABLE, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP78,
+    ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOWED_TOKEN_URI_SCHEMES,
+    ALLOW_MINTING, APPROVAL_EXPIRIES, APPROVED, APPROVED_SPENDERS, ARG_ACCESS_KEY_NAME_1_0_0,
+    ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST, ARG_ADDITIONAL_REQUIRED_METADATA,
+    ARG_ALLOWED_TOKEN_URI_SCHEMES, ARG_ALLOW_MINTING, ARG_APPROVE_ALL, ARG_BLOCKLIST,
+    ARG_BURN_FILTER_CONTRACT, ARG_BURN_MODE, ARG_CALLER, ARG_CALLER_ROLE, ARG_COLLECTION_NAME,
+    ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST, ARG_DATA, ARG_DEADLINE, ARG_ENTRY_POINT,
+    ARG_EVENTS_MODE, ARG_FORCE_TRANSFER_MODE, ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_MODE,
+    ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA, ARG_METADATA_MUTABILITY, ARG_METADATA_SIZE_LIMITS,
+    ARG_MINTING_MODE, ARG_MINT_FILTER_CONTRACT, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
+    ARG_NFT_METADATA_KIND, ARG_NFT_PACKAGE_KEY, ARG_NONCE, ARG_OPEN_SUPPLY, ARG_OPERATOR,
+    ARG_OPERATOR_ALLOWLIST, ARG_OPERATOR_ALLOWLIST_MODE, ARG_OPERATOR_BURN_MODE,
+    ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_OWNER_PUBLIC_KEY,
+    ARG_PACKAGE_HOLDER_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_PAGE_SIZE, ARG_PENDING_TRANSFER_MODE,
+    ARG_REASON, ARG_RECEIPT_NAME, ARG_SIGNATURE, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY,
+    ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_IDS, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER,
+    ARG_TOKEN_RANGE, ARG_TOTAL_TOKEN_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT,
+    ARG_TRANSFER_FILTER_CONTRACTS, ARG_TRANSFER_FILTER_INTERFACE, ARG_WHITELIST_MODE, BLOCKLIST,
+    BURNT_TOKENS, BURN_FILTER_CONTRACT, BURN_FILTER_CONTRACT_METHOD, BURN_MODE, COLLECTION_NAME,
+    COLLECTION_SYMBOL, ENTRY_POINT_APPROVE, ENTRY_POINT_APPROVE_AND_CALL, ENTRY_POINT_BALANCE_OF,
+    ENTRY_POINT_BURN, ENTRY_POINT_CANCEL, ENTRY_POINT_CANCEL_PERMIT, ENTRY_POINT_CLAIM,
+    ENTRY_POINT_FORCE_TRANSFER, ENTRY_POINT_GET_APPROVED, ENTRY_POINT_GET_APPROVED_SPENDERS,
+    ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_IS_LOCKED,
+    ENTRY_POINT_LOCK_TOKEN, ENTRY_POINT_METADATA, ENTRY_POINT_MIGRATE, ENTRY_POINT_MINT,
+    ENTRY_POINT_OWNER_OF, ENTRY_POINT_PERMIT, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE,
+    ENTRY_POINT_REVOKE_TOKEN, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+    ENTRY_POINT_SET_APPROVAL_FOR_TOKENS, ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
+    ENTRY_POINT_TRANSFER, ENTRY_POINT_UNLOCK_TOKEN, ENTRY_POINT_UPDATED_RECEIPTS, EVENTS_MODE,
+    FORCE_TRANSFER_MODE, HASH_BY_INDEX, HASH_KEY_NAME_1_0_0, HOLDER_MODE, IDENTIFIER_MODE,
+    INDEX_BY_HASH, INSTALLER, JSON_SCHEMA, LOCKED_TOKENS, MAX_PAGE_SIZE, MAX_TOTAL_TOKEN_SUPPLY,
+    METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_MUTABILITY, METADATA_NFT721, METADATA_RAW,
+    METADATA_SIZE_LIMITS, MINTING_MODE, MINT_FILTER_CONTRACT, MINT_FILTER_CONTRACT_METHOD,
+    MIN_PAGE_SIZE, NFT_KIND, NFT_METADATA_KIND, NFT_METADATA_KINDS, NUMBER_OF_MINTED_TOKENS,
+    OPEN_SUPPLY, OPERATOR, OPERATORS, OPERATOR_ALLOWLIST, OPERATOR_ALLOWLIST_MODE,
+    OPERATOR_BURN_MODE, OPERATOR_EXPIRIES, OWNED_TOKENS, OWNERSHIP_MODE, PACKAGE_HOLDER_MODE,
+    PACKAGE_OPERATOR_MODE, PACKED_PAGES, PAGE_LIMIT, PAGE_SIZE, PAGE_TABLE, PENDING_TRANSFERS,
+    PENDING_TRANSFER_MODE, PERMIT_NONCES, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP78,
     PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_VERSION, PREFIX_HASH_KEY_NAME, PREFIX_PAGE_DICTIONARY,
-    RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
-    TOTAL_TOKEN_SUPPLY, TRANSFER_FILTER_CONTRACT, TRANSFER_FILTER_CONTRACT_METHOD,
-    UNMATCHED_HASH_COUNT, WHITELIST_MODE,
+    RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OPERATORS,
+    TOKEN_OWNERS, TOTAL_TOKEN_SUPPLY, TRANSFER_FILTER_CONTRACT, TRANSFER_FILTER_CONTRACTS,
+    TRANSFER_FILTER_CONTRACT_METHOD, TRANSFER_FILTER_INTERFACE, UNMATCHED_HASH_COUNT,
+    WHITELIST_MODE,
 };
 use core::convert::{TryFrom, TryInto};
 use error::NFTCoreError;
 use events::{
     events_cep47::{record_cep47_event_dictionary, CEP47Event},
     events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        RevokedForAll, Transfer, VariablesSet,
+        Approval, ApprovalForAll, ApprovalForTokens, ApprovalRevoked, Burn, ForceTransfer,
+        MetadataUpdated, Migration, Mint, PendingTransfer, Revoked, RevokedForAll, SpenderRevoked,
+        Transfer, TransferFilterContractUpdated, TransferFilterContractsUpdated, VariablesSet,
     },
 };
 use metadata::CustomMetadataSchema;
 use modalities::{
-    BurnMode, EventsMode, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-    NFTKind, NFTMetadataKind, NamedKeyConventionMode, OwnerReverseLookupMode, OwnershipMode,
-    Requirement, TokenIdentifier, TransferFilterContractResult, WhitelistMode,
+    BurnMode, EventsMode, ForceTransferMode, MetadataMutability, MintingMode, NFTHolderMode,
+    NFTIdentifierMode, NFTKind, NFTMetadataKind, NamedKeyConventionMode, OperatorAllowlistMode,
+    OwnerReverseLookupMode, OwnershipMode, Permit, Requirement, TokenIdentifier,
+    TransferCallerRole, TransferFilterContractResult, TransferFilterInterface, WhitelistMode,
 };
 use utils::Caller;
 
@@ -120,10 +138,28 @@ pub extern "C" fn init() {
     )
     .unwrap_or_revert();
 
-    if total_token_supply > MAX_TOTAL_TOKEN_SUPPLY {
+    let open_supply: bool = utils::get_named_arg_with_user_errors(
+        ARG_OPEN_SUPPLY,
+        NFTCoreError::MissingOpenSupply,
+        NFTCoreError::InvalidOpenSupply,
+    )
+    .unwrap_or_revert();
+
+    if !open_supply && total_token_supply > MAX_TOTAL_TOKEN_SUPPLY {
         runtime::revert(NFTCoreError::ExceededMaxTotalSupply)
     }
 
+    let page_size: u64 = utils::get_named_arg_with_user_errors(
+        ARG_PAGE_SIZE,
+        NFTCoreError::MissingPageSize,
+        NFTCoreError::InvalidPageSize,
+    )
+    .unwrap_or_revert();
+
+    if !(MIN_PAGE_SIZE..=MAX_PAGE_SIZE).contains(&page_size) {
+        runtime::revert(NFTCoreError::InvalidPageSize)
+    }
+
     let allow_minting: bool = utils::get_named_arg_with_user_errors(
         ARG_ALLOW_MINTING,
         NFTCoreError::MissingMintingStatus,
@@ -209,6 +245,60 @@ pub extern "C" fn init() {
     )
     .unwrap_or_default();
 
+    let package_holder_mode: bool = utils::get_named_arg_with_user_errors(
+        ARG_PACKAGE_HOLDER_MODE,
+        NFTCoreError::MissingPackageHolderMode,
+        NFTCoreError::InvalidPackageHolderMode,
+    )
+    .unwrap_or_revert();
+
+    let operator_allowlist_mode: OperatorAllowlistMode =
+        utils::get_named_arg_with_user_errors::<u8>(
+            ARG_OPERATOR_ALLOWLIST_MODE,
+            NFTCoreError::MissingOperatorAllowlistMode,
+            NFTCoreError::InvalidOperatorAllowlistMode,
+        )
+        .unwrap_or_revert()
+        .try_into()
+        .unwrap_or_revert();
+
+    let operator_allowlist = utils::get_named_arg_with_user_errors::<Vec<Key>>(
+        ARG_OPERATOR_ALLOWLIST,
+        NFTCoreError::MissingOperatorAllowlist,
+        NFTCoreError::InvalidOperatorAllowlist,
+    )
+    .unwrap_or_revert();
+
+    // Revert if approvals are unrestricted and the operator allowlist is not empty
+    if OperatorAllowlistMode::Unrestricted == operator_allowlist_mode
+        && !operator_allowlist.is_empty()
+    {
+        runtime::revert(NFTCoreError::InvalidOperatorAllowlistMode)
+    }
+
+    let pending_transfer_mode: bool = utils::get_named_arg_with_user_errors(
+        ARG_PENDING_TRANSFER_MODE,
+        NFTCoreError::MissingPendingTransferMode,
+        NFTCoreError::InvalidPendingTransferMode,
+    )
+    .unwrap_or_revert();
+
+    let blocklist = utils::get_named_arg_with_user_errors::<Vec<Key>>(
+        ARG_BLOCKLIST,
+        NFTCoreError::MissingBlocklist,
+        NFTCoreError::InvalidBlocklist,
+    )
+    .unwrap_or_revert();
+
+    let force_transfer_mode: ForceTransferMode = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_FORCE_TRANSFER_MODE,
+        NFTCoreError::MissingForceTransferMode,
+        NFTCoreError::InvalidForceTransferMode,
+    )
+    .unwrap_or_revert()
+    .try_into()
+    .unwrap_or_revert();
+
     let receipt_name: String = utils::get_named_arg_with_user_errors(
         ARG_RECEIPT_NAME,
         NFTCoreError::MissingReceiptName,
@@ -337,7 +427,7 @@ pub extern "C" fn init() {
             ContractHash::from(
                 transfer_filter_contract_contract_key
                     .into_hash()
-                    .unwrap_or_revert(),
+                    .unwrap_or_revert_with(NFTCoreError::InvalidTransferFilterContract),
             )
         });
 
@@ -347,6 +437,48 @@ pub extern "C" fn init() {
         runtime::revert(NFTCoreError::TransferFilterContractNeedsTransferableMode)
     }
 
+    let transfer_filter_contracts: Vec<ContractHash> = utils::get_filter_contract_hashes(
+        &utils::get_named_arg_with_user_errors::<Vec<Key>>(
+            ARG_TRANSFER_FILTER_CONTRACTS,
+            NFTCoreError::MissingTransferFilterContracts,
+            NFTCoreError::InvalidTransferFilterContracts,
+        )
+        .unwrap_or_revert(),
+    );
+
+    if ownership_mode != OwnershipMode::Transferable && !transfer_filter_contracts.is_empty() {
+        runtime::revert(NFTCoreError::TransferFilterContractNeedsTransferableMode)
+    }
+
+    // The mint and burn filters may point to the transfer filter contract or to any other contract.
+    let mint_filter_contract_hash: Option<ContractHash> =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_MINT_FILTER_CONTRACT,
+            NFTCoreError::InvalidMintFilterContract,
+        )
+        .unwrap_or_default()
+        .map(|mint_filter_contract_key| {
+            ContractHash::from(
+                mint_filter_contract_key
+                    .into_hash()
+                    .unwrap_or_revert_with(NFTCoreError::InvalidMintFilterContract),
+            )
+        });
+
+    let burn_filter_contract_hash: Option<ContractHash> =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_BURN_FILTER_CONTRACT,
+            NFTCoreError::InvalidBurnFilterContract,
+        )
+        .unwrap_or_default()
+        .map(|burn_filter_contract_key| {
+            ContractHash::from(
+                burn_filter_contract_key
+                    .into_hash()
+                    .unwrap_or_revert_with(NFTCoreError::InvalidBurnFilterContract),
+            )
+        });
+
     // Put all created URefs into the contract's context (necessary to retain access rights,
     // for future use).
     //
@@ -388,6 +520,28 @@ pub extern "C" fn init() {
         NFT_METADATA_KINDS,
         storage::new_uref(nft_metadata_kinds).into(),
     );
+
+    let metadata_size_limits: BTreeMap<NFTMetadataKind, u32> =
+        utils::get_named_arg_with_user_errors::<BTreeMap<u8, u32>>(
+            ARG_METADATA_SIZE_LIMITS,
+            NFTCoreError::MissingMetadataSizeLimits,
+            NFTCoreError::InvalidMetadataSizeLimits,
+        )
+        .unwrap_or_revert()
+        .into_iter()
+        .map(|(metadata_kind, size_limit)| {
+            if size_limit == 0 {
+                runtime::revert(NFTCoreError::InvalidMetadataSizeLimits)
+            }
+            let metadata_kind = NFTMetadataKind::try_from(metadata_kind)
+                .unwrap_or_revert_with(NFTCoreError::InvalidMetadataSizeLimits);
+            (metadata_kind, size_limit)
+        })
+        .collect();
+    runtime::put_key(
+        METADATA_SIZE_LIMITS,
+        storage::new_uref(metadata_size_limits).into(),
+    );
     runtime::put_key(
         IDENTIFIER_MODE,
         storage::new_uref(identifier_mode as u8).into(),
@@ -430,6 +584,20 @@ pub extern "C" fn init() {
     storage::new_dictionary(TOKEN_ISSUERS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(APPROVED).unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(APPROVED_SPENDERS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(APPROVAL_EXPIRIES)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(OPERATOR_EXPIRIES)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(TOKEN_OPERATORS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(PERMIT_NONCES)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(LOCKED_TOKENS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(PENDING_TRANSFERS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(OPERATORS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(BURNT_TOKENS)
@@ -466,16 +634,64 @@ pub extern "C" fn init() {
         PACKAGE_OPERATOR_MODE,
         storage::new_uref(package_operator_mode).into(),
     );
+    runtime::put_key(
+        PACKAGE_HOLDER_MODE,
+        storage::new_uref(package_holder_mode).into(),
+    );
+    runtime::put_key(
+        PENDING_TRANSFER_MODE,
+        storage::new_uref(pending_transfer_mode).into(),
+    );
+
+    storage::new_dictionary(OPERATOR_ALLOWLIST)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+
+    for key in operator_allowlist.iter() {
+        utils::upsert_dictionary_value_from_key(
+            OPERATOR_ALLOWLIST,
+            &utils::encode_dictionary_item_key(*key),
+            true,
+        );
+    }
+
+    runtime::put_key(
+        OPERATOR_ALLOWLIST_MODE,
+        storage::new_uref(operator_allowlist_mode as u8).into(),
+    );
+
+    storage::new_dictionary(BLOCKLIST)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+
+    for key in blocklist.iter() {
+        utils::upsert_dictionary_value_from_key(
+            BLOCKLIST,
+            &utils::encode_dictionary_item_key(*key),
+            true,
+        );
+    }
+    runtime::put_key(
+        FORCE_TRANSFER_MODE,
+        storage::new_uref(force_transfer_mode as u8).into(),
+    );
 
+    // The page size must be stored ahead of sizing the page table.
+    runtime::put_key(PAGE_SIZE, storage::new_uref(page_size).into());
     if vec![
         OwnerReverseLookupMode::Complete,
         OwnerReverseLookupMode::TransfersOnly,
     ]
     .contains(&reporting_mode)
     {
-        let page_table_width = utils::max_number_of_pages(total_token_supply);
+        // In open supply mode the pages are created on demand as tokens are minted.
+        let page_table_width = if open_supply {
+            0u64
+        } else {
+            utils::max_number_of_pages(total_token_supply)
+        };
         runtime::put_key(PAGE_LIMIT, storage::new_uref(page_table_width).into());
     }
+    runtime::put_key(OPEN_SUPPLY, storage::new_uref(open_supply).into());
+    runtime::put_key(PACKED_PAGES, storage::new_uref(true).into());
     runtime::put_key(
         REPORTING_MODE,
         storage::new_uref(reporting_mode as u8).into(),
@@ -488,6 +704,60 @@ pub extern "C" fn init() {
             storage::new_uref(transfer_filter_contract).into(),
         );
     }
+
+    if !transfer_filter_contracts.is_empty() {
+        runtime::put_key(
+            TRANSFER_FILTER_CONTRACTS,
+            storage::new_uref(transfer_filter_contracts).into(),
+        );
+    }
+
+    if let Some(mint_filter_contract) = mint_filter_contract_hash {
+        runtime::put_key(
+            MINT_FILTER_CONTRACT,
+            storage::new_uref(mint_filter_contract).into(),
+        );
+    }
+
+    if let Some(burn_filter_contract) = burn_filter_contract_hash {
+        runtime::put_key(
+            BURN_FILTER_CONTRACT,
+            storage::new_uref(burn_filter_contract).into(),
+        );
+    }
+
+    let transfer_filter_interface: TransferFilterInterface =
+        utils::get_named_arg_with_user_errors::<u8>(
+            ARG_TRANSFER_FILTER_INTERFACE,
+            NFTCoreError::MissingTransferFilterInterface,
+            NFTCoreError::InvalidTransferFilterInterface,
+        )
+        .unwrap_or_revert()
+        .try_into()
+        .unwrap_or_revert();
+    runtime::put_key(
+        TRANSFER_FILTER_INTERFACE,
+        storage::new_uref(transfer_filter_interface as u8).into(),
+    );
+
+    let allowed_token_uri_schemes: Vec<String> = utils::get_named_arg_with_user_errors(
+        ARG_ALLOWED_TOKEN_URI_SCHEMES,
+        NFTCoreError::MissingAllowedTokenURISchemes,
+        NFTCoreError::InvalidAllowedTokenURISchemes,
+    )
+    .unwrap_or_revert();
+
+    if allowed_token_uri_schemes
+        .iter()
+        .any(|scheme| scheme.is_empty())
+    {
+        runtime::revert(NFTCoreError::InvalidAllowedTokenURISchemes)
+    }
+
+    runtime::put_key(
+        ALLOWED_TOKEN_URI_SCHEMES,
+        storage::new_uref(allowed_token_uri_schemes).into(),
+    );
 }
 
 // set_variables allows the user to set any variable or any combination of variables simultaneously.
@@ -553,6 +823,120 @@ pub extern "C" fn set_variables() {
         storage::write(operator_burn_mode_uref, operator_burn_mode);
     }
 
+    if let Some(total_token_supply) = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::InvalidTotalTokenSupply,
+    ) {
+        let total_token_supply_uref = utils::get_uref(
+            TOTAL_TOKEN_SUPPLY,
+            NFTCoreError::MissingTotalTokenSupply,
+            NFTCoreError::InvalidTotalTokenSupply,
+        );
+        let current_total_token_supply: u64 = utils::read_with_user_errors(
+            total_token_supply_uref,
+            NFTCoreError::MissingTotalTokenSupply,
+            NFTCoreError::InvalidTotalTokenSupply,
+        );
+        // The supply can only be raised, as lowering it below the number of minted tokens
+        // would leave the page records of existing owners out of range.
+        if total_token_supply < current_total_token_supply {
+            runtime::revert(NFTCoreError::CannotDecreaseTotalTokenSupply)
+        }
+        let open_supply = utils::is_open_supply();
+        if !open_supply && total_token_supply > MAX_TOTAL_TOKEN_SUPPLY {
+            runtime::revert(NFTCoreError::ExceededMaxTotalSupply)
+        }
+        storage::write(total_token_supply_uref, total_token_supply);
+
+        if !open_supply
+            && vec![
+                OwnerReverseLookupMode::Complete,
+                OwnerReverseLookupMode::TransfersOnly,
+            ]
+            .contains(&utils::get_reporting_mode())
+        {
+            // Existing page tables are widened lazily the next time they are written to.
+            let page_limit_uref = utils::get_uref(
+                PAGE_LIMIT,
+                NFTCoreError::MissingPageLimit,
+                NFTCoreError::InvalidPageLimit,
+            );
+            let current_page_limit: u64 = utils::read_with_user_errors(
+                page_limit_uref,
+                NFTCoreError::MissingPageLimit,
+                NFTCoreError::InvalidPageLimit,
+            );
+            let page_limit = utils::extend_number_of_pages(current_page_limit, total_token_supply);
+            storage::write(page_limit_uref, page_limit);
+        }
+    }
+
+    // Passing `None` removes the transfer filter contract.
+    let updated_transfer_filter_contract = utils::get_optional_named_arg_with_user_errors::<
+        Option<Key>,
+    >(
+        ARG_TRANSFER_FILTER_CONTRACT,
+        NFTCoreError::InvalidTransferFilterContract,
+    )
+    .map(
+        |transfer_filter_contract_key| match transfer_filter_contract_key {
+            Some(transfer_filter_contract_key) => {
+                if OwnershipMode::Transferable != utils::get_ownership_mode().unwrap_or_revert() {
+                    runtime::revert(NFTCoreError::TransferFilterContractNeedsTransferableMode)
+                }
+                let transfer_filter_contract = ContractHash::from(
+                    transfer_filter_contract_key
+                        .into_hash()
+                        .unwrap_or_revert_with(NFTCoreError::InvalidTransferFilterContract),
+                );
+                if runtime::has_key(TRANSFER_FILTER_CONTRACT) {
+                    let transfer_filter_contract_uref = utils::get_uref(
+                        TRANSFER_FILTER_CONTRACT,
+                        NFTCoreError::MissingTransferFilterContract,
+                        NFTCoreError::InvalidTransferFilterContract,
+                    );
+                    storage::write(transfer_filter_contract_uref, transfer_filter_contract);
+                } else {
+                    runtime::put_key(
+                        TRANSFER_FILTER_CONTRACT,
+                        storage::new_uref(transfer_filter_contract).into(),
+                    );
+                }
+                Some(Key::from(transfer_filter_contract))
+            }
+            None => {
+                runtime::remove_key(TRANSFER_FILTER_CONTRACT);
+                None
+            }
+        },
+    );
+
+    // Passing an empty list clears the chained transfer filter contracts.
+    let updated_transfer_filter_contracts =
+        utils::get_optional_named_arg_with_user_errors::<Vec<Key>>(
+            ARG_TRANSFER_FILTER_CONTRACTS,
+            NFTCoreError::InvalidTransferFilterContracts,
+        )
+        .map(|transfer_filter_contract_keys| {
+            let transfer_filter_contracts =
+                utils::get_filter_contract_hashes(&transfer_filter_contract_keys);
+            if transfer_filter_contracts.is_empty() {
+                runtime::remove_key(TRANSFER_FILTER_CONTRACTS);
+            } else {
+                if OwnershipMode::Transferable != utils::get_ownership_mode().unwrap_or_revert() {
+                    runtime::revert(NFTCoreError::TransferFilterContractNeedsTransferableMode)
+                }
+                runtime::put_key(
+                    TRANSFER_FILTER_CONTRACTS,
+                    storage::new_uref(transfer_filter_contracts.clone()).into(),
+                );
+            }
+            transfer_filter_contracts
+                .into_iter()
+                .map(Key::from)
+                .collect::<Vec<Key>>()
+        });
+
     let mut new_acl_whitelist = utils::get_optional_named_arg_with_user_errors::<Vec<Key>>(
         ARG_ACL_WHITELIST,
         NFTCoreError::InvalidACLWhitelist,
@@ -598,6 +982,44 @@ pub extern "C" fn set_variables() {
         }
     }
 
+    if let Some(new_operator_allowlist) = utils::get_optional_named_arg_with_user_errors::<Vec<Key>>(
+        ARG_OPERATOR_ALLOWLIST,
+        NFTCoreError::InvalidOperatorAllowlist,
+    ) {
+        // The operator allowlist only applies when approvals are restricted.
+        if OperatorAllowlistMode::Unrestricted == utils::get_operator_allowlist_mode() {
+            runtime::revert(NFTCoreError::InvalidOperatorAllowlistMode)
+        }
+        // Replace the operator allowlist, existing approvals are left as they are.
+        runtime::remove_key(OPERATOR_ALLOWLIST);
+        storage::new_dictionary(OPERATOR_ALLOWLIST)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        for key in new_operator_allowlist.iter() {
+            utils::upsert_dictionary_value_from_key(
+                OPERATOR_ALLOWLIST,
+                &utils::encode_dictionary_item_key(*key),
+                true,
+            );
+        }
+    }
+
+    if let Some(new_blocklist) = utils::get_optional_named_arg_with_user_errors::<Vec<Key>>(
+        ARG_BLOCKLIST,
+        NFTCoreError::InvalidBlocklist,
+    ) {
+        // Replace the blocklist, tokens already held by newly blocked keys are left as they are.
+        runtime::remove_key(BLOCKLIST);
+        storage::new_dictionary(BLOCKLIST)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        for key in new_blocklist.iter() {
+            utils::upsert_dictionary_value_from_key(
+                BLOCKLIST,
+                &utils::encode_dictionary_item_key(*key),
+                true,
+            );
+        }
+    }
+
     let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
         EVENTS_MODE,
         NFTCoreError::MissingEventsMode,
@@ -612,6 +1034,34 @@ pub extern "C" fn set_variables() {
         EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::VariablesSet),
         EventsMode::CES => casper_event_standard::emit(VariablesSet::new()),
     }
+
+    if let Some(transfer_filter_contract) = updated_transfer_filter_contract {
+        match events_mode {
+            EventsMode::NoEvents => {}
+            EventsMode::CEP47 => {
+                record_cep47_event_dictionary(CEP47Event::TransferFilterContractUpdated {
+                    transfer_filter_contract,
+                })
+            }
+            EventsMode::CES => casper_event_standard::emit(TransferFilterContractUpdated::new(
+                transfer_filter_contract,
+            )),
+        }
+    }
+
+    if let Some(transfer_filter_contracts) = updated_transfer_filter_contracts {
+        match events_mode {
+            EventsMode::NoEvents => {}
+            EventsMode::CEP47 => {
+                record_cep47_event_dictionary(CEP47Event::TransferFilterContractsUpdated {
+                    transfer_filter_contracts,
+                })
+            }
+            EventsMode::CES => casper_event_standard::emit(TransferFilterContractsUpdated::new(
+                transfer_filter_contracts,
+            )),
+        }
+    }
 }
 
 // Mints a new token. Minting will fail if allow_minting is set to false.
@@ -745,6 +1195,24 @@ pub extern "C" fn mint() {
         NFTCoreError::InvalidIdentifier,
     )
     .unwrap_or_default();
+
+    // A token hash derived from the metadata would no longer match the metadata once updated,
+    // so tokens with mutable metadata must be minted with a caller supplied hash.
+    if identifier_mode == NFTIdentifierMode::Hash && optional_token_hash.is_empty() {
+        let metadata_mutability: MetadataMutability =
+            utils::get_stored_value_with_user_errors::<u8>(
+                METADATA_MUTABILITY,
+                NFTCoreError::MissingMetadataMutability,
+                NFTCoreError::InvalidMetadataMutability,
+            )
+            .try_into()
+            .unwrap_or_revert();
+
+        if let MetadataMutability::Mutable = metadata_mutability {
+            runtime::revert(NFTCoreError::MissingCustomTokenHash)
+        }
+    }
+
     let token_identifier: TokenIdentifier = match identifier_mode {
         NFTIdentifierMode::Ordinal => TokenIdentifier::Index(minted_tokens_count),
         NFTIdentifierMode::Hash => TokenIdentifier::Hash(if optional_token_hash.is_empty() {
@@ -783,9 +1251,34 @@ pub extern "C" fn mint() {
         if let OwnershipMode::Assigned | OwnershipMode::Transferable = ownership_mode {
             runtime::get_named_arg(ARG_TOKEN_OWNER)
         } else {
-            caller
+            utils::get_holder_key(caller, contract_package)
         };
 
+    if utils::is_blocked(token_owner_key) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
+
+    if let Some(filter_contract) = utils::get_mint_filter_contract() {
+        let mut args = RuntimeArgs::new();
+        args.insert(ARG_TOKEN_OWNER, token_owner_key).unwrap();
+        args.insert(ARG_CALLER, caller).unwrap();
+
+        match &token_identifier {
+            TokenIdentifier::Index(idx) => {
+                args.insert(ARG_TOKEN_ID, *idx).unwrap();
+            }
+            TokenIdentifier::Hash(hash) => {
+                args.insert(ARG_TOKEN_ID, hash.clone()).unwrap();
+            }
+        }
+
+        let result: TransferFilterContractResult =
+            call_contract::<u8>(filter_contract, MINT_FILTER_CONTRACT_METHOD, args).into();
+        if TransferFilterContractResult::DenyTransfer == result {
+            revert(NFTCoreError::MintFilterContractDenied);
+        }
+    }
+
     utils::upsert_dictionary_value_from_key(
         TOKEN_OWNERS,
         &token_identifier.get_dictionary_item_key(),
@@ -794,7 +1287,7 @@ pub extern "C" fn mint() {
     utils::upsert_dictionary_value_from_key(
         TOKEN_ISSUERS,
         &token_identifier.get_dictionary_item_key(),
-        caller,
+        utils::get_holder_key(caller, contract_package),
     );
     let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
 
@@ -903,16 +1396,11 @@ pub extern "C" fn burn() {
     };
 
     // Check if caller is owner
-    let is_owner = token_owner == caller;
+    let is_owner = token_owner == utils::get_holder_key(caller, contract_package);
 
     // Check if caller is operator to execute burn
-    let is_operator = if !is_owner {
-        let owner_operator_item_key = utils::encode_key_and_value(&token_owner, &caller);
-        utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-            .unwrap_or_default()
-    } else {
-        false
-    };
+    let is_operator =
+        !is_owner && utils::is_token_operator(&token_owner, &caller, &token_identifier);
 
     // With operator package mode check if caller's package is operator to let contract execute burn
     let is_package_operator = if !is_owner && !is_operator {
@@ -925,10 +1413,7 @@ pub extern "C" fn burn() {
             contract_package,
         ) {
             (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&token_owner, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
+                utils::is_token_operator(&token_owner, &contract_package, &token_identifier)
             }
             _ => false,
         }
@@ -941,6 +1426,10 @@ pub extern "C" fn burn() {
         runtime::revert(NFTCoreError::InvalidTokenOwner)
     };
 
+    if !is_owner && !utils::is_allowlisted_caller(caller, contract_package) {
+        runtime::revert(NFTCoreError::UnlistedOperator)
+    }
+
     // It makes sense to keep this token as owned by the caller. It just happens that the caller
     // owns a burnt token. That's all. Similarly, we should probably also not change the
     // owned_tokens dictionary.
@@ -948,32 +1437,9 @@ pub extern "C" fn burn() {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
-    // Mark the token as burnt by adding the token_id to the burnt tokens dictionary.
-    utils::upsert_dictionary_value_from_key::<()>(
-        BURNT_TOKENS,
-        &token_identifier.get_dictionary_item_key(),
-        (),
-    );
-
-    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner);
-
-    let updated_balance =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owned_tokens_item_key) {
-            Some(balance) => {
-                if balance > 0u64 {
-                    balance - 1u64
-                } else {
-                    // This should never happen if contract is implemented correctly.
-                    runtime::revert(NFTCoreError::FatalTokenIdDuplication);
-                }
-            }
-            None => {
-                // This should never happen if contract is implemented correctly.
-                runtime::revert(NFTCoreError::FatalTokenIdDuplication);
-            }
-        };
+    check_burn_filter_contract(token_owner, caller, &token_identifier);
 
-    utils::upsert_dictionary_value_from_key(TOKEN_COUNT, &owned_tokens_item_key, updated_balance);
+    burn_token(token_owner, &token_identifier);
 
     // Emit Burn event.
     let events_mode: EventsMode =
@@ -997,15 +1463,170 @@ pub extern "C" fn burn() {
     }
 }
 
-// Marks an account as approved for an identified token transfer
-#[no_mangle]
-pub extern "C" fn approve() {
-    // If we are in minter or assigned mode it makes no sense to approve an account. Hence we
-    // revert.
-    if let OwnershipMode::Minter | OwnershipMode::Assigned =
-        utils::get_ownership_mode().unwrap_or_revert()
-    {
-        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+// Reverts if the burn filter contract, when there is one, denies the caller burning the token.
+fn check_burn_filter_contract(token_owner: Key, caller: Key, token_identifier: &TokenIdentifier) {
+    if let Some(filter_contract) = utils::get_burn_filter_contract() {
+        let mut args = RuntimeArgs::new();
+        args.insert(ARG_TOKEN_OWNER, token_owner).unwrap();
+        args.insert(ARG_CALLER, caller).unwrap();
+
+        match token_identifier {
+            TokenIdentifier::Index(idx) => {
+                args.insert(ARG_TOKEN_ID, *idx).unwrap();
+            }
+            TokenIdentifier::Hash(hash) => {
+                args.insert(ARG_TOKEN_ID, hash.clone()).unwrap();
+            }
+        }
+
+        let result: TransferFilterContractResult =
+            call_contract::<u8>(filter_contract, BURN_FILTER_CONTRACT_METHOD, args).into();
+        if TransferFilterContractResult::DenyTransfer == result {
+            revert(NFTCoreError::BurnFilterContractDenied);
+        }
+    }
+}
+
+// Marks a token as burnt and removes it from the balance and pages of its owner, along with its
+// pending transfer and lock.
+fn burn_token(token_owner: Key, token_identifier: &TokenIdentifier) {
+    // Mark the token as burnt by adding the token_id to the burnt tokens dictionary.
+    utils::upsert_dictionary_value_from_key::<()>(
+        BURNT_TOKENS,
+        &token_identifier.get_dictionary_item_key(),
+        (),
+    );
+
+    utils::clear_pending_transfer(token_identifier);
+    utils::clear_token_lock(token_identifier);
+
+    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner);
+
+    let updated_balance =
+        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owned_tokens_item_key) {
+            Some(balance) => {
+                if balance > 0u64 {
+                    balance - 1u64
+                } else {
+                    // This should never happen if contract is implemented correctly.
+                    runtime::revert(NFTCoreError::FatalTokenIdDuplication);
+                }
+            }
+            None => {
+                // This should never happen if contract is implemented correctly.
+                runtime::revert(NFTCoreError::FatalTokenIdDuplication);
+            }
+        };
+
+    utils::upsert_dictionary_value_from_key(TOKEN_COUNT, &owned_tokens_item_key, updated_balance);
+
+    if let OwnerReverseLookupMode::Complete | OwnerReverseLookupMode::TransfersOnly =
+        utils::get_reporting_mode()
+    {
+        // Hash identified tokens yet to be matched to an index are not recorded in any page.
+        let token_index = match token_identifier {
+            TokenIdentifier::Index(token_index) => Some(*token_index),
+            TokenIdentifier::Hash(_) => utils::get_dictionary_value_from_key::<u64>(
+                INDEX_BY_HASH,
+                &token_identifier.get_dictionary_item_key(),
+            ),
+        };
+        if let Some(token_index) = token_index {
+            utils::remove_page_entry_and_page_record(token_index, &owned_tokens_item_key);
+        }
+    }
+}
+
+// Revokes a token issued in the Assigned ownership mode by burning it. Only the installer or the
+// issuer of the token can revoke it, regardless of the burn mode.
+#[no_mangle]
+pub extern "C" fn revoke_token() {
+    if let OwnershipMode::Minter | OwnershipMode::Transferable =
+        utils::get_ownership_mode().unwrap_or_revert()
+    {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+
+    let reason: u8 = utils::get_named_arg_with_user_errors(
+        ARG_REASON,
+        NFTCoreError::MissingRevocationReason,
+        NFTCoreError::InvalidRevocationReason,
+    )
+    .unwrap_or_revert();
+
+    let token_owner = match utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier_dictionary_key,
+    ) {
+        Some(owner) => owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    // Revert if caller is neither the installer nor the issuer of the token.
+    let revoker = utils::get_installer_or_issuer(&token_identifier)
+        .unwrap_or_revert_with(NFTCoreError::InvalidTokenRevoker);
+
+    // Revocation bypasses the burn mode and operator rules, but not the burn filter contract.
+    check_burn_filter_contract(token_owner, revoker, &token_identifier);
+
+    burn_token(token_owner, &token_identifier);
+
+    // Emit Revoked event.
+    let events_mode: EventsMode =
+        EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+            EVENTS_MODE,
+            NFTCoreError::MissingEventsMode,
+            NFTCoreError::InvalidEventsMode,
+        ))
+        .unwrap_or_revert();
+
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => casper_event_standard::emit(Revoked::new(
+            token_owner,
+            token_identifier,
+            revoker,
+            reason,
+        )),
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Revoked {
+            owner: token_owner,
+            token_id: token_identifier,
+            revoker,
+            reason,
+        }),
+    }
+}
+
+// Marks an account as approved for an identified token transfer
+#[no_mangle]
+pub extern "C" fn approve() {
+    approve_spender();
+}
+
+// Approves the spender from the runtime args and returns the token owner, the spender and the
+// approved token.
+fn approve_spender() -> (Key, Key, TokenIdentifier) {
+    // If we are in minter or assigned mode it makes no sense to approve an account. Hence we
+    // revert.
+    if let OwnershipMode::Minter | OwnershipMode::Assigned =
+        utils::get_ownership_mode().unwrap_or_revert()
+    {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
     }
 
     let (caller, contract_package): (Key, Option<Key>) =
@@ -1052,13 +1673,8 @@ pub extern "C" fn approve() {
 
     // Revert if caller is not token owner nor operator.
     // Only the token owner or an operator can approve an account
-    let is_owner = caller == owner;
-    let is_operator = !is_owner
-        && utils::get_dictionary_value_from_key::<bool>(
-            OPERATORS,
-            &utils::encode_key_and_value(&owner, &caller),
-        )
-        .unwrap_or_default();
+    let is_owner = utils::get_holder_key(caller, contract_package) == owner;
+    let is_operator = !is_owner && utils::is_token_operator(&owner, &caller, &token_id);
 
     let is_package_operator = if !is_owner && !is_operator {
         match (
@@ -1070,10 +1686,7 @@ pub extern "C" fn approve() {
             contract_package,
         ) {
             (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&owner, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
+                utils::is_token_operator(&owner, &contract_package, &token_id)
             }
             _ => false,
         }
@@ -1108,11 +1721,18 @@ pub extern "C" fn approve() {
         runtime::revert(NFTCoreError::InvalidAccount);
     }
 
-    utils::upsert_dictionary_value_from_key(
-        APPROVED,
-        &token_identifier_dictionary_key,
-        Some(spender),
-    );
+    if !utils::is_allowlisted_operator(spender) {
+        runtime::revert(NFTCoreError::UnlistedOperator);
+    }
+
+    if utils::is_blocked(spender) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
+
+    let expires_at = utils::get_optional_approval_expiry();
+
+    // Approving adds the spender next to those already approved for the token.
+    utils::add_approved_spender(&token_id, spender, expires_at);
 
     let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
         crate::constants::EVENTS_MODE,
@@ -1124,13 +1744,57 @@ pub extern "C" fn approve() {
     // Emit Approval event.
     match events_mode {
         EventsMode::NoEvents => {}
-        EventsMode::CES => casper_event_standard::emit(Approval::new(owner, spender, token_id)),
+        EventsMode::CES => {
+            casper_event_standard::emit(Approval::new(owner, spender, token_id.clone()))
+        }
         EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::ApprovalGranted {
             owner,
             spender,
-            token_id,
+            token_id: token_id.clone(),
         }),
     };
+
+    (owner, spender, token_id)
+}
+
+// Approves a spender contract package for an identified token and calls one of the entry points of
+// its latest version with the token, so that listing a token on a marketplace takes a single deploy
+#[no_mangle]
+pub extern "C" fn approve_and_call() {
+    let (owner, spender, token_id) = approve_spender();
+
+    let spender_package_hash = match spender {
+        Key::Hash(package_hash) => ContractPackageHash::new(package_hash),
+        _ => runtime::revert(NFTCoreError::InvalidApproveAndCallSpender),
+    };
+
+    let entry_point = utils::get_named_arg_with_user_errors::<String>(
+        ARG_ENTRY_POINT,
+        NFTCoreError::MissingEntryPointName,
+        NFTCoreError::InvalidEntryPointName,
+    )
+    .unwrap_or_revert();
+
+    let data: Option<Bytes> = utils::get_optional_named_arg_with_user_errors(
+        ARG_DATA,
+        NFTCoreError::InvalidApproveAndCallData,
+    );
+
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_TOKEN_OWNER, owner).unwrap();
+    match &token_id {
+        TokenIdentifier::Index(idx) => {
+            args.insert(ARG_TOKEN_ID, *idx).unwrap();
+        }
+        TokenIdentifier::Hash(hash) => {
+            args.insert(ARG_TOKEN_ID, hash.clone()).unwrap();
+        }
+    }
+    args.insert(ARG_DATA, data).unwrap();
+
+    // The spender is approved by now, so it may transfer the token from within the call. The
+    // called entry point must return Unit.
+    runtime::call_versioned_contract::<()>(spender_package_hash, None, &entry_point, args);
 }
 
 // Revokes an account as approved for an identified token transfer
@@ -1188,13 +1852,8 @@ pub extern "C" fn revoke() {
 
     // Revert if caller is not the token owner or an operator. Only the token owner / operators can
     // revoke an approved account
-    let is_owner = caller == owner;
-    let is_operator = !is_owner
-        && utils::get_dictionary_value_from_key::<bool>(
-            OPERATORS,
-            &utils::encode_key_and_value(&owner, &caller),
-        )
-        .unwrap_or_default();
+    let is_owner = utils::get_holder_key(caller, contract_package) == owner;
+    let is_operator = !is_owner && utils::is_token_operator(&owner, &caller, &token_id);
 
     let is_package_operator = if !is_owner && !is_operator {
         match (
@@ -1206,10 +1865,7 @@ pub extern "C" fn revoke() {
             contract_package,
         ) {
             (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&owner, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
+                utils::is_token_operator(&owner, &contract_package, &token_id)
             }
             _ => false,
         }
@@ -1226,11 +1882,19 @@ pub extern "C" fn revoke() {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
-    utils::upsert_dictionary_value_from_key(
-        APPROVED,
-        &token_identifier_dictionary_key,
-        Option::<Key>::None,
+    // Revoke a single spender if one is given, otherwise every spender approved for the token.
+    let spender = utils::get_optional_named_arg_with_user_errors::<Key>(
+        ARG_SPENDER,
+        NFTCoreError::InvalidSpenderAccountHash,
     );
+    match spender {
+        Some(spender) => {
+            if !utils::remove_approved_spender(&token_id, spender) {
+                runtime::revert(NFTCoreError::UnapprovedSpender)
+            }
+        }
+        None => utils::clear_approved_spenders(&token_id),
+    }
 
     let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
         crate::constants::EVENTS_MODE,
@@ -1239,11 +1903,23 @@ pub extern "C" fn revoke() {
     ))
     .unwrap_or_revert();
 
-    // Emit ApprovalRevoked event.
-    match events_mode {
-        EventsMode::NoEvents => {}
-        EventsMode::CES => casper_event_standard::emit(ApprovalRevoked::new(owner, token_id)),
-        EventsMode::CEP47 => {
+    // Emit SpenderRevoked event for a single spender, ApprovalRevoked event for all of them.
+    match (events_mode, spender) {
+        (EventsMode::NoEvents, _) => {}
+        (EventsMode::CES, Some(spender)) => {
+            casper_event_standard::emit(SpenderRevoked::new(owner, spender, token_id))
+        }
+        (EventsMode::CES, None) => {
+            casper_event_standard::emit(ApprovalRevoked::new(owner, token_id))
+        }
+        (EventsMode::CEP47, Some(spender)) => {
+            record_cep47_event_dictionary(CEP47Event::SpenderRevoked {
+                owner,
+                spender,
+                token_id,
+            })
+        }
+        (EventsMode::CEP47, None) => {
             record_cep47_event_dictionary(CEP47Event::ApprovalRevoked { owner, token_id })
         }
     };
@@ -1271,7 +1947,9 @@ pub extern "C" fn set_approval_for_all() {
 
     let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
         Caller::Session(account_hash) => account_hash.into(),
-        Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+        Caller::StoredCaller(contract_hash, contract_package_hash) => {
+            utils::get_holder_key(contract_hash.into(), Some(contract_package_hash.into()))
+        }
     };
 
     let operator = utils::get_named_arg_with_user_errors::<Key>(
@@ -1286,9 +1964,30 @@ pub extern "C" fn set_approval_for_all() {
         runtime::revert(NFTCoreError::InvalidAccount);
     }
 
+    // Only approvals are subject to the operator allowlist, revocations are always allowed.
+    if approve_all && !utils::is_allowlisted_operator(operator) {
+        runtime::revert(NFTCoreError::UnlistedOperator);
+    }
+
+    if approve_all && utils::is_blocked(operator) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
+
+    // The expiry only applies to an approval, a revocation clears it.
+    let expires_at = if approve_all {
+        utils::get_optional_approval_expiry()
+    } else {
+        None
+    };
+
     // Depending on approve_all we either approve all or disapprove all.
     let owner_operator_item_key = utils::encode_key_and_value(&caller, &operator);
     utils::upsert_dictionary_value_from_key(OPERATORS, &owner_operator_item_key, approve_all);
+    utils::upsert_dictionary_value_from_key(
+        OPERATOR_EXPIRIES,
+        &owner_operator_item_key,
+        expires_at,
+    );
 
     let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
         EVENTS_MODE,
@@ -1323,15 +2022,24 @@ pub extern "C" fn set_approval_for_all() {
     }
 }
 
-// Returns a boolean state if an account is operator for an owner
+// Approves the specified operator for a subset of the owner's tokens, given as token indices and/or
+// an index range. Passing neither revokes the operator's scoped approval.
 #[no_mangle]
-pub extern "C" fn is_approved_for_all() {
-    let owner_key = utils::get_named_arg_with_user_errors::<Key>(
-        ARG_TOKEN_OWNER,
-        NFTCoreError::MissingAccountHash,
-        NFTCoreError::InvalidAccountHash,
-    )
-    .unwrap_or_revert();
+pub extern "C" fn set_approval_for_tokens() {
+    // If we are in minter or assigned mode it makes no sense to approve an operator. Hence we
+    // revert.
+    if let OwnershipMode::Minter | OwnershipMode::Assigned =
+        utils::get_ownership_mode().unwrap_or_revert()
+    {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
+        Caller::Session(account_hash) => account_hash.into(),
+        Caller::StoredCaller(contract_hash, contract_package_hash) => {
+            utils::get_holder_key(contract_hash.into(), Some(contract_package_hash.into()))
+        }
+    };
 
     let operator = utils::get_named_arg_with_user_errors::<Key>(
         ARG_OPERATOR,
@@ -1340,53 +2048,378 @@ pub extern "C" fn is_approved_for_all() {
     )
     .unwrap_or_revert();
 
-    let owner_operator_item_key = utils::encode_key_and_value(&owner_key, &operator);
+    // If caller tries to approve itself as operator that's probably a mistake and we revert.
+    if caller == operator {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    let token_ids = utils::get_optional_named_arg_with_user_errors::<Vec<u64>>(
+        ARG_TOKEN_IDS,
+        NFTCoreError::InvalidTokenIds,
+    )
+    .unwrap_or_default();
 
-    let is_operator =
-        utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-            .unwrap_or_default();
+    let token_range = utils::get_optional_named_arg_with_user_errors::<(u64, u64)>(
+        ARG_TOKEN_RANGE,
+        NFTCoreError::InvalidTokenRange,
+    );
 
-    let operator_cl_value =
-        CLValue::from_t(is_operator).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    let token_ranges = utils::merge_token_ranges(token_ids, token_range);
 
-    runtime::ret(operator_cl_value);
+    if !token_ranges.is_empty() && !utils::is_allowlisted_operator(operator) {
+        runtime::revert(NFTCoreError::UnlistedOperator);
+    }
+
+    if !token_ranges.is_empty() && utils::is_blocked(operator) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
+
+    let expires_at = if token_ranges.is_empty() {
+        None
+    } else {
+        utils::get_optional_approval_expiry()
+    };
+
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_OPERATORS,
+        &utils::encode_key_and_value(&caller, &operator),
+        (token_ranges.clone(), expires_at),
+    );
+
+    let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => {
+            casper_event_standard::emit(ApprovalForTokens::new(caller, operator, token_ranges))
+        }
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::ApprovalForTokens {
+            owner: caller,
+            operator,
+            token_ranges,
+        }),
+    }
 }
 
-// Transfers token from token owner to specified account. Transfer will go through if caller is
-// owner or an approved account or an operator. Transfer will fail if OwnershipMode is Minter or
-// Assigned.
+// Grants an approval signed off-chain by the token owner, so that the spender does not depend on
+// the owner sending an approve deploy. Without a token identifier the spender is approved as an
+// operator for all of the owner's tokens.
 #[no_mangle]
-pub extern "C" fn transfer() {
-    // If we are in minter or assigned mode we are not allowed to transfer ownership of token, hence
-    // we revert.
+pub extern "C" fn permit() {
+    // If we are in minter or assigned mode it makes no sense to approve an account. Hence we
+    // revert.
     if let OwnershipMode::Minter | OwnershipMode::Assigned =
         utils::get_ownership_mode().unwrap_or_revert()
     {
         runtime::revert(NFTCoreError::InvalidOwnershipMode)
     }
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
+    let owner_public_key = utils::get_named_arg_with_user_errors::<PublicKey>(
+        ARG_OWNER_PUBLIC_KEY,
+        NFTCoreError::MissingOwnerPublicKey,
+        NFTCoreError::InvalidOwnerPublicKey,
     )
-    .try_into()
     .unwrap_or_revert();
+    let owner = Key::Account(owner_public_key.to_account_hash());
 
-    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let spender = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_SPENDER,
+        NFTCoreError::MissingSpenderAccountHash,
+        NFTCoreError::InvalidSpenderAccountHash,
+    )
+    .unwrap_or_revert();
 
-    // We assume we cannot transfer burnt tokens
-    if utils::is_token_burned(&token_identifier) {
-        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    // If the owner approves itself that's probably a mistake and we revert.
+    if owner == spender {
+        runtime::revert(NFTCoreError::InvalidAccount);
     }
 
-    let owner = match utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier.get_dictionary_item_key(),
-    ) {
-        Some(owner) => owner,
-        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
-    };
+    if !utils::is_allowlisted_operator(spender) {
+        runtime::revert(NFTCoreError::UnlistedOperator);
+    }
+
+    if utils::is_blocked(spender) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
+
+    let nonce = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_NONCE,
+        NFTCoreError::MissingPermitNonce,
+        NFTCoreError::InvalidPermitNonce,
+    )
+    .unwrap_or_revert();
+
+    let deadline = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_DEADLINE,
+        NFTCoreError::MissingPermitDeadline,
+        NFTCoreError::InvalidPermitDeadline,
+    )
+    .unwrap_or_revert();
+
+    if u64::from(runtime::get_blocktime()) > deadline {
+        runtime::revert(NFTCoreError::PermitExpired);
+    }
+
+    let expires_at = utils::get_optional_approval_expiry();
+
+    let signature = utils::get_named_arg_with_user_errors::<Bytes>(
+        ARG_SIGNATURE,
+        NFTCoreError::MissingPermitSignature,
+        NFTCoreError::InvalidPermitSignature,
+    )
+    .unwrap_or_revert();
+
+    // Nonces are tracked per owner so that each signed permit can only be used once.
+    let owner_item_key = utils::encode_dictionary_item_key(owner);
+    let expected_nonce =
+        utils::get_dictionary_value_from_key::<u64>(PERMIT_NONCES, &owner_item_key)
+            .unwrap_or_default();
+    if nonce != expected_nonce {
+        runtime::revert(NFTCoreError::InvalidPermitNonce);
+    }
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let permit = Permit {
+        contract_package_hash: utils::get_current_contract_package_hash(),
+        owner,
+        spender,
+        token_id: utils::get_optional_token_identifier_from_runtime_args(&identifier_mode),
+        nonce,
+        deadline,
+        expires_at,
+    };
+
+    if !utils::is_valid_permit_signature(&permit, &owner_public_key, &signature) {
+        runtime::revert(NFTCoreError::InvalidPermitSignature);
+    }
+
+    utils::upsert_dictionary_value_from_key(PERMIT_NONCES, &owner_item_key, nonce + 1);
+
+    let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    match permit.token_id {
+        Some(token_id) => {
+            let token_identifier_dictionary_key = token_id.get_dictionary_item_key();
+
+            // Only the token owner can sign a permit for one of its tokens.
+            match utils::get_dictionary_value_from_key::<Key>(
+                TOKEN_OWNERS,
+                &token_identifier_dictionary_key,
+            ) {
+                Some(token_owner) if token_owner == owner => {}
+                Some(_) => runtime::revert(NFTCoreError::InvalidTokenOwner),
+                None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+            }
+
+            // We assume a burnt token cannot be approved
+            if utils::is_token_burned(&token_id) {
+                runtime::revert(NFTCoreError::PreviouslyBurntToken)
+            }
+
+            utils::add_approved_spender(&token_id, spender, permit.expires_at);
+
+            match events_mode {
+                EventsMode::NoEvents => {}
+                EventsMode::CES => {
+                    casper_event_standard::emit(Approval::new(owner, spender, token_id))
+                }
+                EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::ApprovalGranted {
+                    owner,
+                    spender,
+                    token_id,
+                }),
+            }
+        }
+        None => {
+            let owner_operator_item_key = utils::encode_key_and_value(&owner, &spender);
+            utils::upsert_dictionary_value_from_key(OPERATORS, &owner_operator_item_key, true);
+            utils::upsert_dictionary_value_from_key(
+                OPERATOR_EXPIRIES,
+                &owner_operator_item_key,
+                permit.expires_at,
+            );
+
+            match events_mode {
+                EventsMode::NoEvents => {}
+                EventsMode::CES => casper_event_standard::emit(ApprovalForAll::new(owner, spender)),
+                EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::ApprovalForAll {
+                    owner,
+                    operator: spender,
+                }),
+            }
+        }
+    }
+}
+
+// Cancels the unused permit signed by the calling account by moving its nonce past the one the
+// permit was signed with.
+#[no_mangle]
+pub extern "C" fn cancel_permit() {
+    let owner_item_key = utils::encode_dictionary_item_key(Key::Account(runtime::get_caller()));
+    let nonce = utils::get_dictionary_value_from_key::<u64>(PERMIT_NONCES, &owner_item_key)
+        .unwrap_or_default();
+    utils::upsert_dictionary_value_from_key(PERMIT_NONCES, &owner_item_key, nonce + 1);
+}
+
+// Returns a boolean state if an account is operator for an owner
+#[no_mangle]
+pub extern "C" fn is_approved_for_all() {
+    let owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TOKEN_OWNER,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let operator = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_OPERATOR,
+        NFTCoreError::MissingOperator,
+        NFTCoreError::InvalidOperator,
+    )
+    .unwrap_or_revert();
+
+    // Operators whose approval has expired are reported as not approved.
+    let is_operator = utils::is_operator(&owner_key, &operator);
+
+    let operator_cl_value =
+        CLValue::from_t(is_operator).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(operator_cl_value);
+}
+
+// Locks a token against transfers until it is unlocked. Only the installer or the issuer of the
+// token can lock it.
+#[no_mangle]
+pub extern "C" fn lock_token() {
+    update_token_lock(true);
+}
+
+// Lets a locked token be transferred again. Only the installer or the issuer of the token can
+// unlock it.
+#[no_mangle]
+pub extern "C" fn unlock_token() {
+    update_token_lock(false);
+}
+
+fn update_token_lock(locked: bool) {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+
+    if utils::get_dictionary_value_from_key::<Key>(TOKEN_OWNERS, &token_identifier_dictionary_key)
+        .is_none()
+    {
+        runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey)
+    }
+
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    // Revert if caller is neither the installer nor the issuer of the token.
+    if utils::get_installer_or_issuer(&token_identifier).is_none() {
+        runtime::revert(NFTCoreError::InvalidTokenLocker)
+    }
+
+    utils::upsert_dictionary_value_from_key(
+        LOCKED_TOKENS,
+        &token_identifier_dictionary_key,
+        locked,
+    );
+}
+
+// Returns whether a token is locked against transfers.
+#[no_mangle]
+pub extern "C" fn is_locked() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    let is_locked = utils::is_token_locked(&token_identifier);
+
+    let is_locked_cl_value =
+        CLValue::from_t(is_locked).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(is_locked_cl_value);
+}
+
+// Transfers token from token owner to specified account. Transfer will go through if caller is
+// owner or an approved account or an operator. Transfer will fail if OwnershipMode is Minter or
+// Assigned.
+#[no_mangle]
+pub extern "C" fn transfer() {
+    // If we are in minter or assigned mode we are not allowed to transfer ownership of token, hence
+    // we revert.
+    if let OwnershipMode::Minter | OwnershipMode::Assigned =
+        utils::get_ownership_mode().unwrap_or_revert()
+    {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    // We assume we cannot transfer burnt tokens
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    // Locked tokens cannot be transferred until unlocked by the installer or their issuer
+    if utils::is_token_locked(&token_identifier) {
+        runtime::revert(NFTCoreError::LockedToken)
+    }
+
+    // Tokens waiting to be claimed cannot be transferred until their pending transfer is cancelled
+    if utils::get_pending_transfer(&token_identifier).is_some() {
+        runtime::revert(NFTCoreError::PendingTransfer)
+    }
+
+    let owner = match utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    ) {
+        Some(owner) => owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
 
     let source_owner_key = utils::get_named_arg_with_user_errors::<Key>(
         ARG_SOURCE_KEY,
@@ -1408,30 +2441,33 @@ pub extern "C" fn transfer() {
         };
 
     // Check if caller is owner
-    let is_owner = owner == caller;
+    let is_owner = owner == utils::get_holder_key(caller, contract_package);
 
     // Check if caller is approved to execute transfer
-    let is_approved = !is_owner
-        && match utils::get_dictionary_value_from_key::<Option<Key>>(
-            APPROVED,
-            &token_identifier.get_dictionary_item_key(),
-        ) {
-            Some(Some(maybe_approved)) => caller == maybe_approved,
-            Some(None) | None => false,
-        };
-
-    // Check if caller is operator to execute transfer
-    let is_operator = if !is_owner && !is_approved {
-        let owner_operator_item_key = utils::encode_key_and_value(&source_owner_key, &caller);
-        utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-            .unwrap_or_default()
+    let approved = if is_owner {
+        vec![]
     } else {
-        false
+        utils::get_approved(&token_identifier)
     };
+    let is_approved = approved.contains(&caller);
+
+    // Check if caller is operator to execute transfer
+    let is_operator = !is_owner
+        && !is_approved
+        && utils::is_token_operator(&source_owner_key, &caller, &token_identifier);
+
+    // Contract packages approved as the spender of the token, e.g. through approve_and_call, may
+    // transfer it from any of their contract versions.
+    let is_package_approved = !is_owner
+        && !is_approved
+        && !is_operator
+        && contract_package.map_or(false, |contract_package| {
+            approved.contains(&contract_package)
+        });
 
     // With operator package mode check if caller's package is operator to let contract execute
     // transfer
-    let is_package_operator = if !is_owner && !is_approved && !is_operator {
+    let is_package_operator = if !is_owner && !is_approved && !is_operator && !is_package_approved {
         match (
             utils::get_stored_value_with_user_errors::<bool>(
                 PACKAGE_OPERATOR_MODE,
@@ -1441,10 +2477,7 @@ pub extern "C" fn transfer() {
             contract_package,
         ) {
             (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&source_owner_key, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
+                utils::is_token_operator(&source_owner_key, &contract_package, &token_identifier)
             }
             _ => false,
         }
@@ -1452,10 +2485,31 @@ pub extern "C" fn transfer() {
         false
     };
 
-    if let Some(filter_contract) = utils::get_transfer_filter_contract() {
+    // Revert if caller is not owner nor approved nor an operator.
+    if !is_owner && !is_approved && !is_package_approved && !is_operator && !is_package_operator {
+        runtime::revert(NFTCoreError::InvalidTokenOwner);
+    }
+
+    if !is_owner && !utils::is_allowlisted_caller(caller, contract_package) {
+        runtime::revert(NFTCoreError::UnlistedOperator);
+    }
+
+    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TARGET_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    if utils::is_blocked(target_owner_key) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
+
+    let filter_contracts = utils::get_transfer_filter_contracts();
+    if !filter_contracts.is_empty() {
         let mut args = RuntimeArgs::new();
         args.insert(ARG_SOURCE_KEY, source_owner_key).unwrap();
-        args.insert(ARG_TARGET_KEY, owner).unwrap();
+        args.insert(ARG_TARGET_KEY, target_owner_key).unwrap();
 
         match &token_identifier {
             TokenIdentifier::Index(idx) => {
@@ -1466,26 +2520,283 @@ pub extern "C" fn transfer() {
             }
         }
 
-        let result: TransferFilterContractResult =
-            call_contract::<u8>(filter_contract, TRANSFER_FILTER_CONTRACT_METHOD, args).into();
-        if TransferFilterContractResult::DenyTransfer == result {
-            revert(NFTCoreError::TransferFilterContractDenied);
+        if TransferFilterInterface::V2 == utils::get_transfer_filter_interface() {
+            let caller_role = if is_owner {
+                TransferCallerRole::Owner
+            } else if is_approved || is_package_approved {
+                TransferCallerRole::Approved
+            } else {
+                TransferCallerRole::Operator
+            };
+            let data: Option<Bytes> = utils::get_optional_named_arg_with_user_errors(
+                ARG_DATA,
+                NFTCoreError::InvalidTransferData,
+            );
+            args.insert(ARG_CALLER, caller).unwrap();
+            args.insert(ARG_CALLER_ROLE, caller_role as u8).unwrap();
+            args.insert(ARG_DATA, data).unwrap();
+        }
+
+        // Every filter contract of the chain must let the transfer proceed.
+        for filter_contract in filter_contracts {
+            let result: TransferFilterContractResult = call_contract::<u8>(
+                filter_contract,
+                TRANSFER_FILTER_CONTRACT_METHOD,
+                args.clone(),
+            )
+            .into();
+            if TransferFilterContractResult::DenyTransfer == result {
+                revert(NFTCoreError::TransferFilterContractDenied);
+            }
         }
     }
 
-    // Revert if caller is not owner nor approved nor an operator.
-    if !is_owner && !is_approved && !is_operator && !is_package_operator {
-        runtime::revert(NFTCoreError::InvalidTokenOwner);
+    // In the pending transfer mode, tokens their owner sends to contracts or to accounts unknown to
+    // the contract are parked until the target claims them, so that the owner can cancel mistaken
+    // transfers. Approved spenders and operators, e.g. escrows and marketplaces, transfer right
+    // away.
+    if is_owner && utils::get_pending_transfer_mode() && !utils::is_known_holder(target_owner_key) {
+        if let Some(receipt) = park_transfer(&token_identifier, owner, target_owner_key) {
+            runtime::ret(receipt)
+        }
+        return;
     }
 
-    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
-        ARG_TARGET_KEY,
-        NFTCoreError::MissingAccountHash,
-        NFTCoreError::InvalidAccountHash,
+    let receipt = transfer_token(
+        &identifier_mode,
+        &token_identifier,
+        source_owner_key,
+        target_owner_key,
+    );
+
+    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    ))
+    .unwrap_or_revert();
+
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Transfer {
+            sender: caller,
+            recipient: target_owner_key,
+            token_id: token_identifier,
+        }),
+        EventsMode::CES => {
+            // Emit Transfer event.
+            let spender = if caller == owner { None } else { Some(caller) };
+            casper_event_standard::emit(Transfer::new(
+                owner,
+                spender,
+                target_owner_key,
+                token_identifier,
+            ));
+        }
+    }
+
+    if let Some(receipt) = receipt {
+        runtime::ret(receipt)
+    }
+}
+
+// Records the pending transfer of a token to its target and clears its approvals. The token stays
+// with its owner, so the receipt returned in the reverse lookup modes is that of the owner's page.
+fn park_transfer(
+    token_identifier: &TokenIdentifier,
+    owner: Key,
+    target_owner_key: Key,
+) -> Option<CLValue> {
+    utils::upsert_dictionary_value_from_key(
+        PENDING_TRANSFERS,
+        &token_identifier.get_dictionary_item_key(),
+        Some(target_owner_key),
+    );
+
+    utils::clear_approved_spenders(token_identifier);
+
+    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    ))
+    .unwrap_or_revert();
+
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::PendingTransfer {
+            owner,
+            recipient: target_owner_key,
+            token_id: token_identifier.clone(),
+        }),
+        EventsMode::CES => casper_event_standard::emit(PendingTransfer::new(
+            owner,
+            target_owner_key,
+            token_identifier.clone(),
+        )),
+    }
+
+    let owner_item_key = utils::encode_dictionary_item_key(owner);
+    let (page_table_entry, page_uref) = match utils::get_reporting_mode() {
+        OwnerReverseLookupMode::NoLookUp => return None,
+        // The owner's page already records the token since it was minted.
+        OwnerReverseLookupMode::Complete => {
+            let page_table_entry =
+                utils::get_token_index(token_identifier) / utils::get_page_size();
+            let page_uref = utils::get_uref(
+                &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+                NFTCoreError::MissingPageUref,
+                NFTCoreError::InvalidPageUref,
+            );
+            (page_table_entry, page_uref)
+        }
+        // The owner's page lacks the token in the transfers only mode until it first moves.
+        OwnerReverseLookupMode::TransfersOnly => utils::add_page_entry_and_page_record(
+            utils::get_token_index(token_identifier),
+            &owner_item_key,
+            false,
+        ),
+    };
+
+    let receipt = CLValue::from_t((
+        utils::get_receipt_name(page_table_entry),
+        Key::dictionary(page_uref, owner_item_key.as_bytes()),
+    ))
+    .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    Some(receipt)
+}
+
+// Completes the pending transfer of a token to its target, which must be the caller.
+#[no_mangle]
+pub extern "C" fn claim() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
     )
+    .try_into()
     .unwrap_or_revert();
 
-    if NFTIdentifierMode::Hash == identifier_mode && runtime::get_key(OWNED_TOKENS).is_some() {
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    if utils::is_token_locked(&token_identifier) {
+        runtime::revert(NFTCoreError::LockedToken)
+    }
+
+    let target_owner_key = match utils::get_pending_transfer(&token_identifier) {
+        Some(target_owner_key) => target_owner_key,
+        None => runtime::revert(NFTCoreError::MissingPendingTransfer),
+    };
+
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller().unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
+        };
+
+    // Revert if caller is not the target of the pending transfer.
+    if utils::get_holder_key(caller, contract_package) != target_owner_key {
+        runtime::revert(NFTCoreError::InvalidClaimant)
+    }
+
+    if utils::is_blocked(target_owner_key) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
+
+    let owner = match utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    ) {
+        Some(owner) => owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    // Moving the token clears its pending transfer.
+    let receipt = transfer_token(&identifier_mode, &token_identifier, owner, target_owner_key);
+
+    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    ))
+    .unwrap_or_revert();
+
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Transfer {
+            sender: owner,
+            recipient: target_owner_key,
+            token_id: token_identifier,
+        }),
+        EventsMode::CES => casper_event_standard::emit(Transfer::new(
+            owner,
+            None,
+            target_owner_key,
+            token_identifier,
+        )),
+    }
+
+    if let Some(receipt) = receipt {
+        runtime::ret(receipt)
+    }
+}
+
+// Cancels the pending transfer of a token, which can then be transferred again. Only the token
+// owner can cancel it.
+#[no_mangle]
+pub extern "C" fn cancel() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    if utils::get_pending_transfer(&token_identifier).is_none() {
+        runtime::revert(NFTCoreError::MissingPendingTransfer)
+    }
+
+    let owner = match utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    ) {
+        Some(owner) => owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller().unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
+        };
+
+    if utils::get_holder_key(caller, contract_package) != owner {
+        runtime::revert(NFTCoreError::InvalidTokenOwner)
+    }
+
+    utils::clear_pending_transfer(&token_identifier);
+}
+
+// Moves a token from its source owner to the target owner, updating balances, approvals, pending
+// transfers and pages. Returns the receipt of the updated page in the reverse lookup modes.
+fn transfer_token(
+    identifier_mode: &NFTIdentifierMode,
+    token_identifier: &TokenIdentifier,
+    source_owner_key: Key,
+    target_owner_key: Key,
+) -> Option<CLValue> {
+    if NFTIdentifierMode::Hash == *identifier_mode && runtime::get_key(OWNED_TOKENS).is_some() {
         if utils::should_migrate_token_hashes(source_owner_key) {
             utils::migrate_token_hashes(source_owner_key)
         }
@@ -1539,24 +2850,105 @@ pub extern "C" fn transfer() {
         updated_from_account_balance,
     );
 
-    // Update the to_account balance
-    let updated_to_account_balance =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &target_owner_item_key) {
-            Some(balance) => balance + 1u64,
-            None => 1u64,
-        };
+    // Update the to_account balance
+    let updated_to_account_balance =
+        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &target_owner_item_key) {
+            Some(balance) => balance + 1u64,
+            None => 1u64,
+        };
+
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_COUNT,
+        &target_owner_item_key,
+        updated_to_account_balance,
+    );
+
+    utils::clear_approved_spenders(token_identifier);
+    utils::clear_pending_transfer(token_identifier);
+
+    let reporting_mode = utils::get_reporting_mode();
+
+    if let OwnerReverseLookupMode::Complete | OwnerReverseLookupMode::TransfersOnly = reporting_mode
+    {
+        // Update to_account owned_tokens. Revert if owned_tokens list is not found
+        let tokens_count = utils::get_token_index(token_identifier);
+        if OwnerReverseLookupMode::TransfersOnly == reporting_mode {
+            utils::add_page_entry_and_page_record(tokens_count, &source_owner_item_key, false);
+        }
+
+        let (page_table_entry, page_uref) = utils::update_page_entry_and_page_record(
+            tokens_count,
+            &source_owner_item_key,
+            &target_owner_item_key,
+        );
+
+        let owned_tokens_actual_key = Key::dictionary(page_uref, source_owner_item_key.as_bytes());
+
+        let receipt_string = utils::get_receipt_name(page_table_entry);
+
+        let receipt = CLValue::from_t((receipt_string, owned_tokens_actual_key))
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+        Some(receipt)
+    } else {
+        None
+    }
+}
+
+// Transfers a token from its owner to the specified account regardless of the approval of the
+// owner, token locks and transfer filters, e.g. to enforce court-ordered reassignments or recover
+// tokens from lost keys. Only the installer can force transfers, and only if the force transfer
+// mode was enabled at installation.
+#[no_mangle]
+pub extern "C" fn force_transfer() {
+    if ForceTransferMode::Disabled == utils::get_force_transfer_mode() {
+        runtime::revert(NFTCoreError::InvalidForceTransferMode)
+    }
+
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    // Only the installing account can force transfers.
+    if installer != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    let owner = match utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    ) {
+        Some(owner) => owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TARGET_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
 
-    utils::upsert_dictionary_value_from_key(
-        TOKEN_COUNT,
-        &target_owner_item_key,
-        updated_to_account_balance,
-    );
+    if utils::is_blocked(target_owner_key) {
+        runtime::revert(NFTCoreError::BlockedKey);
+    }
 
-    utils::upsert_dictionary_value_from_key(
-        APPROVED,
-        &token_identifier.get_dictionary_item_key(),
-        Option::<Key>::None,
-    );
+    let receipt = transfer_token(&identifier_mode, &token_identifier, owner, target_owner_key);
 
     let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
         EVENTS_MODE,
@@ -1567,45 +2959,19 @@ pub extern "C" fn transfer() {
 
     match events_mode {
         EventsMode::NoEvents => {}
-        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Transfer {
-            sender: caller,
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::ForceTransfer {
+            owner,
             recipient: target_owner_key,
-            token_id: token_identifier.clone(),
+            token_id: token_identifier,
         }),
-        EventsMode::CES => {
-            // Emit Transfer event.
-            let spender = if caller == owner { None } else { Some(caller) };
-            casper_event_standard::emit(Transfer::new(
-                owner,
-                spender,
-                target_owner_key,
-                token_identifier.clone(),
-            ));
-        }
+        EventsMode::CES => casper_event_standard::emit(ForceTransfer::new(
+            owner,
+            target_owner_key,
+            token_identifier,
+        )),
     }
 
-    let reporting_mode = utils::get_reporting_mode();
-
-    if let OwnerReverseLookupMode::Complete | OwnerReverseLookupMode::TransfersOnly = reporting_mode
-    {
-        // Update to_account owned_tokens. Revert if owned_tokens list is not found
-        let tokens_count = utils::get_token_index(&token_identifier);
-        if OwnerReverseLookupMode::TransfersOnly == reporting_mode {
-            utils::add_page_entry_and_page_record(tokens_count, &source_owner_item_key, false);
-        }
-
-        let (page_table_entry, page_uref) = utils::update_page_entry_and_page_record(
-            tokens_count,
-            &source_owner_item_key,
-            &target_owner_item_key,
-        );
-
-        let owned_tokens_actual_key = Key::dictionary(page_uref, source_owner_item_key.as_bytes());
-
-        let receipt_string = utils::get_receipt_name(page_table_entry);
-
-        let receipt = CLValue::from_t((receipt_string, owned_tokens_actual_key))
-            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    if let Some(receipt) = receipt {
         runtime::ret(receipt)
     }
 }
@@ -1722,10 +3088,30 @@ pub extern "C" fn metadata() {
     runtime::revert(NFTCoreError::MissingTokenMetaData)
 }
 
-// Returns approved account hash for a specified token identifier, throws error if token id is not
+// Returns the approved spenders for a specified token identifier, throws error if token id is not
 // valid
 #[no_mangle]
 pub extern "C" fn get_approved() {
+    // The first live approval stands for the token, as with a single approved spender.
+    let maybe_approved = get_live_approved_spenders().into_iter().next();
+
+    let approved_cl_value = CLValue::from_t(maybe_approved)
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(approved_cl_value);
+}
+
+#[no_mangle]
+pub extern "C" fn get_approved_spenders() {
+    let approved_cl_value = CLValue::from_t(get_live_approved_spenders())
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(approved_cl_value);
+}
+
+// Returns the spenders approved for the token identified by the runtime args. Expired approvals are
+// left out.
+fn get_live_approved_spenders() -> Vec<Key> {
     let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
         IDENTIFIER_MODE,
         NFTCoreError::MissingIdentifierMode,
@@ -1755,18 +3141,7 @@ pub extern "C" fn get_approved() {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
-    let maybe_approved = match utils::get_dictionary_value_from_key::<Option<Key>>(
-        APPROVED,
-        &token_identifier.get_dictionary_item_key(),
-    ) {
-        Some(maybe_approved) => maybe_approved,
-        None => None,
-    };
-
-    let approved_cl_value = CLValue::from_t(maybe_approved)
-        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
-
-    runtime::ret(approved_cl_value);
+    utils::get_approved(&token_identifier)
 }
 
 #[no_mangle]
@@ -1801,7 +3176,9 @@ pub extern "C" fn set_token_metadata() {
     if let Some(token_owner_key) = token_owner {
         let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
             Caller::Session(account_hash) => account_hash.into(),
-            Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                utils::get_holder_key(contract_hash.into(), Some(contract_package_hash.into()))
+            }
         };
         if caller != token_owner_key {
             runtime::revert(NFTCoreError::InvalidTokenOwner)
@@ -1985,7 +3362,10 @@ pub extern "C" fn migrate() {
         } else {
             update_token_supply();
         }
+    } else if !runtime::has_key(PACKED_PAGES) {
+        utils::set_aside_legacy_page_table();
     }
+    runtime::put_key(PACKED_PAGES, storage::new_uref(true).into());
 
     let metadata_kind: NFTMetadataKind = utils::get_stored_value_with_user_errors(
         NFT_METADATA_KIND,
@@ -2019,7 +3399,10 @@ pub extern "C" fn migrate() {
             .try_into()
             .unwrap_or_revert_with(NFTCoreError::InvalidEventsMode);
         match (current_events_mode, requested_events_mode) {
-            (EventsMode::CES, EventsMode::CES) => casper_event_standard::emit(Migration::new()),
+            (EventsMode::CES, EventsMode::CES) => {
+                utils::update_events_schemas();
+                casper_event_standard::emit(Migration::new());
+            }
             (_, EventsMode::CES) => {
                 // Initialize events structures.
                 utils::init_events();
@@ -2032,7 +3415,10 @@ pub extern "C" fn migrate() {
     } else {
         match current_events_mode {
             EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Migrate),
-            EventsMode::CES => casper_event_standard::emit(Migration::new()),
+            EventsMode::CES => {
+                utils::update_events_schemas();
+                casper_event_standard::emit(Migration::new());
+            }
             _ => {
                 // Store "no events" mode in case it was never stored like version < 1.2
                 if !runtime::has_key(EVENTS_MODE) {
@@ -2103,6 +3489,45 @@ pub extern "C" fn migrate() {
         storage::new_dictionary(OPERATORS)
             .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     }
+    // Add approved spenders dict
+    if runtime::get_key(APPROVED_SPENDERS).is_none() {
+        storage::new_dictionary(APPROVED_SPENDERS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add approval expiries dicts
+    if runtime::get_key(APPROVAL_EXPIRIES).is_none() {
+        storage::new_dictionary(APPROVAL_EXPIRIES)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    if runtime::get_key(OPERATOR_EXPIRIES).is_none() {
+        storage::new_dictionary(OPERATOR_EXPIRIES)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add scoped operators dict
+    if runtime::get_key(TOKEN_OPERATORS).is_none() {
+        storage::new_dictionary(TOKEN_OPERATORS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add permit nonces dict
+    if runtime::get_key(PERMIT_NONCES).is_none() {
+        storage::new_dictionary(PERMIT_NONCES)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add blocklist dict
+    if runtime::get_key(BLOCKLIST).is_none() {
+        storage::new_dictionary(BLOCKLIST)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add pending transfers dict
+    if runtime::get_key(PENDING_TRANSFERS).is_none() {
+        storage::new_dictionary(PENDING_TRANSFERS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add locked tokens dict
+    if runtime::get_key(LOCKED_TOKENS).is_none() {
+        storage::new_dictionary(LOCKED_TOKENS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
 
     utils::migrate_contract_whitelist_to_acl_whitelist();
 }
@@ -2112,7 +3537,9 @@ pub extern "C" fn updated_receipts() {
     if let OwnerReverseLookupMode::Complete = utils::get_reporting_mode() {
         let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
             Caller::Session(account_hash) => account_hash.into(),
-            Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                utils::get_holder_key(contract_hash.into(), Some(contract_package_hash.into()))
+            }
         };
 
         let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
@@ -2130,14 +3557,18 @@ pub extern "C" fn updated_receipts() {
 
         let token_owner_item_key = utils::encode_dictionary_item_key(caller);
 
+        let page_table_uref = utils::get_uref(
+            PAGE_TABLE,
+            NFTCoreError::MissingPageTableURef,
+            NFTCoreError::InvalidPageTableURef,
+        );
         let page_table =
-            utils::get_dictionary_value_from_key::<Vec<bool>>(PAGE_TABLE, &token_owner_item_key)
-                .unwrap_or_default();
+            utils::get_page_table(page_table_uref, &token_owner_item_key).unwrap_or_default();
 
         let mut updated_receipts: Vec<(String, Key)> = vec![];
 
-        for (page_table_entry, allocated) in page_table.into_iter().enumerate() {
-            if !allocated {
+        for page_table_entry in 0..page_table.len() * 8 {
+            if !utils::is_bit_set(&page_table, page_table_entry as u64) {
                 continue;
             }
             let page_uref = utils::get_uref(
@@ -2166,7 +3597,9 @@ pub extern "C" fn register_owner() {
         let owner_key = match utils::get_ownership_mode().unwrap_or_revert() {
             OwnershipMode::Minter => match utils::get_verified_caller().unwrap_or_revert() {
                 Caller::Session(account_hash) => account_hash.into(),
-                Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+                Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                    utils::get_holder_key(contract_hash.into(), Some(contract_package_hash.into()))
+                }
             },
             OwnershipMode::Assigned | OwnershipMode::Transferable => {
                 utils::get_named_arg_with_user_errors::<Key>(
@@ -2186,10 +3619,7 @@ pub extern "C" fn register_owner() {
 
         let owner_item_key = utils::encode_dictionary_item_key(owner_key);
 
-        if storage::dictionary_get::<Vec<bool>>(page_table_uref, &owner_item_key)
-            .unwrap_or_revert()
-            .is_none()
-        {
+        if utils::get_page_table(page_table_uref, &owner_item_key).is_none() {
             let page_table_width = utils::get_stored_value_with_user_errors::<u64>(
                 PAGE_LIMIT,
                 NFTCoreError::MissingPageLimit,
@@ -2198,7 +3628,7 @@ pub extern "C" fn register_owner() {
             storage::dictionary_put(
                 page_table_uref,
                 &owner_item_key,
-                vec![false; page_table_width as usize],
+                Bytes::from(utils::new_bitset(page_table_width)),
             );
         }
         let collection_name = utils::get_stored_value_with_user_errors::<String>(
@@ -2238,6 +3668,8 @@ fn generate_entry_points() -> EntryPoints {
             Parameter::new(ARG_ACL_WHITELIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(ARG_ACL_PACKAGE_MODE, CLType::Bool),
             Parameter::new(ARG_PACKAGE_OPERATOR_MODE, CLType::Bool),
+            Parameter::new(ARG_PACKAGE_HOLDER_MODE, CLType::Bool),
+            Parameter::new(ARG_PENDING_TRANSFER_MODE, CLType::Bool),
             Parameter::new(ARG_JSON_SCHEMA, CLType::String),
             Parameter::new(ARG_RECEIPT_NAME, CLType::String),
             Parameter::new(ARG_IDENTIFIER_MODE, CLType::U8),
@@ -2251,6 +3683,36 @@ fn generate_entry_points() -> EntryPoints {
                 ARG_TRANSFER_FILTER_CONTRACT,
                 CLType::Option(Box::new(CLType::Key)),
             ),
+            Parameter::new(ARG_TRANSFER_FILTER_INTERFACE, CLType::U8),
+            Parameter::new(
+                ARG_TRANSFER_FILTER_CONTRACTS,
+                CLType::List(Box::new(CLType::Key)),
+            ),
+            Parameter::new(
+                ARG_MINT_FILTER_CONTRACT,
+                CLType::Option(Box::new(CLType::Key)),
+            ),
+            Parameter::new(
+                ARG_BURN_FILTER_CONTRACT,
+                CLType::Option(Box::new(CLType::Key)),
+            ),
+            Parameter::new(
+                ARG_ALLOWED_TOKEN_URI_SCHEMES,
+                CLType::List(Box::new(CLType::String)),
+            ),
+            Parameter::new(
+                ARG_METADATA_SIZE_LIMITS,
+                CLType::Map {
+                    key: Box::new(CLType::U8),
+                    value: Box::new(CLType::U32),
+                },
+            ),
+            Parameter::new(ARG_OPEN_SUPPLY, CLType::Bool),
+            Parameter::new(ARG_PAGE_SIZE, CLType::U64),
+            Parameter::new(ARG_OPERATOR_ALLOWLIST_MODE, CLType::U8),
+            Parameter::new(ARG_OPERATOR_ALLOWLIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(ARG_BLOCKLIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(ARG_FORCE_TRANSFER_MODE, CLType::U8),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -2279,6 +3741,17 @@ fn generate_entry_points() -> EntryPoints {
             Parameter::new(ARG_ACL_PACKAGE_MODE, CLType::Bool),
             Parameter::new(ARG_PACKAGE_OPERATOR_MODE, CLType::Bool),
             Parameter::new(ARG_OPERATOR_BURN_MODE, CLType::Bool),
+            Parameter::new(ARG_TOTAL_TOKEN_SUPPLY, CLType::U64),
+            Parameter::new(
+                ARG_TRANSFER_FILTER_CONTRACT,
+                CLType::Option(Box::new(CLType::Key)),
+            ),
+            Parameter::new(
+                ARG_TRANSFER_FILTER_CONTRACTS,
+                CLType::List(Box::new(CLType::Key)),
+            ),
+            Parameter::new(ARG_OPERATOR_ALLOWLIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(ARG_BLOCKLIST, CLType::List(Box::new(CLType::Key))),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -2354,6 +3827,20 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint approves a spender contract package as approve does, then calls the
+    // "entry_point" of its latest version with the token owner, the token_id and the optional data.
+    // It reverts if the spender is not a contract package hash.
+    let approve_and_call = EntryPoint::new(
+        ENTRY_POINT_APPROVE_AND_CALL,
+        vec![
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_ENTRY_POINT, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint revokes an approved account to transfer tokens. It reverts
     // if token_id is invalid, if caller is not the owner, if token has already
     // been burnt, if caller tries to approve itself.
@@ -2379,6 +3866,112 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint approves an operator to transfer the caller's tokens whose indices are listed
+    // in token_ids or fall within token_range. It replaces any previous scoped approval of the
+    // operator, and revokes it when neither argument is passed.
+    let set_approval_for_tokens = EntryPoint::new(
+        ENTRY_POINT_SET_APPROVAL_FOR_TOKENS,
+        vec![Parameter::new(ARG_OPERATOR, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint grants an approval signed by the owner's public key. The optional token_id or
+    // token_hash selects the approved token, otherwise the spender becomes an operator. The
+    // optional expires_at is signed along with the permit and bounds the granted approval.
+    let permit = EntryPoint::new(
+        ENTRY_POINT_PERMIT,
+        vec![
+            Parameter::new(ARG_OWNER_PUBLIC_KEY, CLType::PublicKey),
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_NONCE, CLType::U64),
+            Parameter::new(ARG_DEADLINE, CLType::U64),
+            Parameter::new(ARG_SIGNATURE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint invalidates the unused permit signed by the caller for its current nonce.
+    let cancel_permit = EntryPoint::new(
+        ENTRY_POINT_CANCEL_PERMIT,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint revokes a token in the Assigned ownership mode by burning it. It reverts if
+    // the caller is neither the installer nor the issuer of the token.
+    let revoke_token = EntryPoint::new(
+        ENTRY_POINT_REVOKE_TOKEN,
+        vec![Parameter::new(ARG_REASON, CLType::U8)], // <- either HASH or INDEX
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint transfers a token regardless of the approval of its owner. It reverts if the
+    // force transfer mode is disabled or if the caller is not the installer.
+    let force_transfer = EntryPoint::new(
+        ENTRY_POINT_FORCE_TRANSFER,
+        vec![Parameter::new(ARG_TARGET_KEY, CLType::Key)], // <- either HASH or INDEX
+        CLType::Tuple2([Box::new(CLType::String), Box::new(CLType::Key)]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint completes the pending transfer of a token. It reverts if the caller is not
+    // the target of the pending transfer.
+    let claim = EntryPoint::new(
+        ENTRY_POINT_CLAIM,
+        vec![], // <- either HASH or INDEX
+        CLType::Tuple2([Box::new(CLType::String), Box::new(CLType::Key)]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint cancels the pending transfer of a token. It reverts if the caller is not the
+    // token owner.
+    let cancel = EntryPoint::new(
+        ENTRY_POINT_CANCEL,
+        vec![], // <- either HASH or INDEX
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint locks a token against transfers. It reverts if the caller is neither the
+    // installer nor the issuer of the token.
+    let lock_token = EntryPoint::new(
+        ENTRY_POINT_LOCK_TOKEN,
+        vec![], // <- either HASH or INDEX
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint lifts the lock of a token. It reverts if the caller is neither the installer
+    // nor the issuer of the token.
+    let unlock_token = EntryPoint::new(
+        ENTRY_POINT_UNLOCK_TOKEN,
+        vec![], // <- either HASH or INDEX
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns whether a token is locked against transfers.
+    let is_locked = EntryPoint::new(
+        ENTRY_POINT_IS_LOCKED,
+        vec![], // <- either HASH or INDEX
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint returns if an account is operator for a token owner
     let is_approved_for_all = EntryPoint::new(
         ENTRY_POINT_IS_APPROVED_FOR_ALL,
@@ -2411,6 +4004,16 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint returns all the approved spenders associated with the provided token_id
+    // Reverts if token has been burnt.
+    let get_approved_spenders = EntryPoint::new(
+        ENTRY_POINT_GET_APPROVED_SPENDERS,
+        vec![], // <- either HASH or INDEX
+        CLType::List(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint returns number of owned tokens associated with the provided token holder
     let balance_of = EntryPoint::new(
         ENTRY_POINT_BALANCE_OF,
@@ -2486,20 +4089,35 @@ fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(burn);
     entry_points.add_entry_point(transfer);
     entry_points.add_entry_point(approve);
+    entry_points.add_entry_point(approve_and_call);
     entry_points.add_entry_point(revoke);
     entry_points.add_entry_point(owner_of);
     entry_points.add_entry_point(balance_of);
     entry_points.add_entry_point(get_approved);
+    entry_points.add_entry_point(get_approved_spenders);
     entry_points.add_entry_point(metadata);
     entry_points.add_entry_point(set_approval_for_all);
+    entry_points.add_entry_point(set_approval_for_tokens);
+    entry_points.add_entry_point(permit);
+    entry_points.add_entry_point(cancel_permit);
     entry_points.add_entry_point(is_approved_for_all);
     entry_points.add_entry_point(set_token_metadata);
     entry_points.add_entry_point(migrate);
     entry_points.add_entry_point(updated_receipts);
     entry_points.add_entry_point(register_owner);
+    entry_points.add_entry_point(lock_token);
+    entry_points.add_entry_point(unlock_token);
+    entry_points.add_entry_point(is_locked);
+    entry_points.add_entry_point(revoke_token);
+    entry_points.add_entry_point(force_transfer);
+    entry_points.add_entry_point(claim);
+    entry_points.add_entry_point(cancel);
     entry_points
 }
 
+// Kept out of line so that the locals of the installation arguments do not add up with the stack
+// height of `migrate_contract` when upgrading.
+#[inline(never)]
 fn install_contract() {
     // Represents the name of the NFT collection
     // This value cannot be changed after installation.
@@ -2522,7 +4140,7 @@ fn install_contract() {
 
     // This represents the total number of NFTs that will
     // be minted by a specific instance of a contract.
-    // This value cannot be changed after installation.
+    // This value can only be raised after installation.
     let total_token_supply: u64 = utils::get_named_arg_with_user_errors(
         ARG_TOTAL_TOKEN_SUPPLY,
         NFTCoreError::MissingTotalTokenSupply,
@@ -2534,10 +4152,66 @@ fn install_contract() {
         runtime::revert(NFTCoreError::CannotInstallWithZeroSupply)
     }
 
-    if total_token_supply > MAX_TOTAL_TOKEN_SUPPLY {
+    // Open supply collections are not bound by the maximum total token supply,
+    // as their pages are created on demand rather than at installation.
+    // This value cannot be changed after installation.
+    let open_supply: bool = utils::get_optional_named_arg_with_user_errors(
+        ARG_OPEN_SUPPLY,
+        NFTCoreError::InvalidOpenSupply,
+    )
+    .unwrap_or(false);
+
+    if !open_supply && total_token_supply > MAX_TOTAL_TOKEN_SUPPLY {
         runtime::revert(NFTCoreError::ExceededMaxTotalSupply)
     }
 
+    // The number of tokens tracked by each page of the owner reverse lookup.
+    // This value cannot be changed after installation.
+    let page_size: u64 = utils::get_optional_named_arg_with_user_errors(
+        ARG_PAGE_SIZE,
+        NFTCoreError::InvalidPageSize,
+    )
+    .unwrap_or(utils::DEFAULT_PAGE_SIZE);
+
+    if !(MIN_PAGE_SIZE..=MAX_PAGE_SIZE).contains(&page_size) {
+        runtime::revert(NFTCoreError::InvalidPageSize)
+    }
+
+    // Represents whether the accounts and contracts that can be approved as spenders or operators
+    // are restricted to an allowlist managed by the installer. Refer to the enum
+    // `OperatorAllowlistMode` in the `src/modalities.rs` file for details.
+    // This value cannot be changed after installation.
+    let operator_allowlist_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_OPERATOR_ALLOWLIST_MODE,
+        NFTCoreError::InvalidOperatorAllowlistMode,
+    )
+    .unwrap_or(0u8);
+
+    // The accounts and contract packages that can be approved in the restricted operator
+    // allowlist mode. This value can be replaced through set_variables.
+    let operator_allowlist: Vec<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_OPERATOR_ALLOWLIST,
+        NFTCoreError::InvalidOperatorAllowlist,
+    )
+    .unwrap_or_default();
+
+    // The accounts and contract packages barred from holding tokens and from being approved. This
+    // value can be replaced through set_variables.
+    let blocklist: Vec<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_BLOCKLIST,
+        NFTCoreError::InvalidBlocklist,
+    )
+    .unwrap_or_default();
+
+    // Represents whether the installer can transfer tokens regardless of the approval of their
+    // owner. Refer to the enum `ForceTransferMode` in the `src/modalities.rs` file for details.
+    // This value cannot be changed after installation.
+    let force_transfer_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_FORCE_TRANSFER_MODE,
+        NFTCoreError::InvalidForceTransferMode,
+    )
+    .unwrap_or(0u8);
+
     let allow_minting: bool = utils::get_optional_named_arg_with_user_errors(
         ARG_ALLOW_MINTING,
         NFTCoreError::InvalidMintingStatus,
@@ -2630,6 +4304,18 @@ fn install_contract() {
     )
     .unwrap_or_default();
 
+    let package_holder_mode: bool = utils::get_optional_named_arg_with_user_errors::<bool>(
+        ARG_PACKAGE_HOLDER_MODE,
+        NFTCoreError::InvalidPackageHolderMode,
+    )
+    .unwrap_or_default();
+
+    let pending_transfer_mode: bool = utils::get_optional_named_arg_with_user_errors::<bool>(
+        ARG_PENDING_TRANSFER_MODE,
+        NFTCoreError::InvalidPendingTransferMode,
+    )
+    .unwrap_or_default();
+
     // Represents the schema for the metadata for a given NFT contract instance.
     // Refer to the `NFTMetadataKind` enum in src/utils for details.
     // This value cannot be changed after installation.
@@ -2652,6 +4338,15 @@ fn install_contract() {
     )
     .unwrap_or_default();
 
+    // Maximum length in bytes of the token metadata accepted for each metadata kind,
+    // keyed by the `NFTMetadataKind` as a u8. Kinds without an entry are not limited.
+    // This value cannot be changed after installation.
+    let metadata_size_limits: BTreeMap<u8, u32> = utils::get_optional_named_arg_with_user_errors(
+        ARG_METADATA_SIZE_LIMITS,
+        NFTCoreError::InvalidMetadataSizeLimits,
+    )
+    .unwrap_or_default();
+
     // The JSON schema representation of the NFT which will be minted.
     // This value cannot be changed after installation.
     let json_schema: String = utils::get_optional_named_arg_with_user_errors(
@@ -2681,10 +4376,6 @@ fn install_contract() {
     )
     .unwrap_or_revert();
 
-    if identifier_mode == 1 && metadata_mutability == 1 {
-        runtime::revert(NFTCoreError::InvalidMetadataMutability)
-    }
-
     // Represents whether the minted tokens can be burnt.
     // This value cannot be changed post installation. Refer to `BurnMode` in
     // `src/modalities.rs` for further details.
@@ -2718,10 +4409,64 @@ fn install_contract() {
             NFTCoreError::InvalidTransferFilterContract,
         );
 
+    // Further filter contracts evaluated in order after the transfer filter contract.
+    let transfer_filter_contracts: Vec<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_CONTRACTS,
+        NFTCoreError::InvalidTransferFilterContracts,
+    )
+    .unwrap_or_default();
+
+    let mint_filter_contract_key: Option<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_MINT_FILTER_CONTRACT,
+        NFTCoreError::InvalidMintFilterContract,
+    );
+
+    let burn_filter_contract_key: Option<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_BURN_FILTER_CONTRACT,
+        NFTCoreError::InvalidBurnFilterContract,
+    );
+
+    // Filter contracts are called by their contract hash, any other kind of key is rejected
+    // before the contract gets installed.
+    for (filter_contract_key, error) in [
+        (
+            transfer_filter_contract_contract_key,
+            NFTCoreError::InvalidTransferFilterContract,
+        ),
+        (
+            mint_filter_contract_key,
+            NFTCoreError::InvalidMintFilterContract,
+        ),
+        (
+            burn_filter_contract_key,
+            NFTCoreError::InvalidBurnFilterContract,
+        ),
+    ] {
+        if matches!(filter_contract_key, Some(key) if key.into_hash().is_none()) {
+            runtime::revert(error)
+        }
+    }
+
+    // The arguments the transfer filter contract is called with, V1 being the original interface.
+    // Refer to `src/modalities.rs` for further details.
+    let transfer_filter_interface: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_INTERFACE,
+        NFTCoreError::InvalidTransferFilterInterface,
+    )
+    .unwrap_or(TransferFilterInterface::V1 as u8);
+
     if ownership_mode == 0 && minting_mode == 0 && reporting_mode == 1 {
         runtime::revert(NFTCoreError::InvalidReportingMode)
     }
 
+    // An optional list of URI prefixes (e.g. "https://", "ipfs://") a CEP78 token_uri
+    // must start with. An empty list allows any token_uri.
+    let allowed_token_uri_schemes: Vec<String> = utils::get_optional_named_arg_with_user_errors(
+        ARG_ALLOWED_TOKEN_URI_SCHEMES,
+        NFTCoreError::InvalidAllowedTokenURISchemes,
+    )
+    .unwrap_or_default();
+
     let entry_points = generate_entry_points();
 
     let named_keys = {
@@ -2793,8 +4538,22 @@ fn install_contract() {
         ARG_EVENTS_MODE => events_mode,
         ARG_ACL_PACKAGE_MODE => acl_package_mode,
         ARG_PACKAGE_OPERATOR_MODE => package_operator_mode,
+        ARG_PACKAGE_HOLDER_MODE => package_holder_mode,
+        ARG_PENDING_TRANSFER_MODE => pending_transfer_mode,
         ARG_TRANSFER_FILTER_CONTRACT =>
         transfer_filter_contract_contract_key,
+        ARG_TRANSFER_FILTER_INTERFACE => transfer_filter_interface,
+        ARG_TRANSFER_FILTER_CONTRACTS => transfer_filter_contracts,
+        ARG_MINT_FILTER_CONTRACT => mint_filter_contract_key,
+        ARG_BURN_FILTER_CONTRACT => burn_filter_contract_key,
+        ARG_ALLOWED_TOKEN_URI_SCHEMES => allowed_token_uri_schemes,
+        ARG_METADATA_SIZE_LIMITS => metadata_size_limits,
+        ARG_OPEN_SUPPLY => open_supply,
+        ARG_PAGE_SIZE => page_size,
+        ARG_OPERATOR_ALLOWLIST_MODE => operator_allowlist_mode,
+        ARG_OPERATOR_ALLOWLIST => operator_allowlist,
+        ARG_BLOCKLIST => blocklist,
+        ARG_FORCE_TRANSFER_MODE => force_transfer_mode,
     };
 
     // Call contract to initialize it
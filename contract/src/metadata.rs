@@ -194,6 +194,12 @@ pub(crate) fn validate_metadata(
     metadata_kind: &NFTMetadataKind,
     token_metadata: String,
 ) -> Result<String, NFTCoreError> {
+    // Checked ahead of any parsing so oversized metadata is rejected as cheaply as possible.
+    if let Some(size_limit) = utils::get_metadata_size_limit(metadata_kind) {
+        if token_metadata.len() > size_limit as usize {
+            return Err(NFTCoreError::MetadataSizeLimitExceeded);
+        }
+    }
     let token_schema = get_metadata_schema(metadata_kind);
     match metadata_kind {
         NFTMetadataKind::CEP78 => {
@@ -215,6 +221,18 @@ pub(crate) fn validate_metadata(
                     runtime::revert(NFTCoreError::InvalidCEP99Metadata)
                 }
             }
+            if !is_sha256_hex_digest(&metadata.checksum) {
+                return Err(NFTCoreError::InvalidCEP78Checksum);
+            }
+            // An empty list of allowed schemes places no restriction on the token_uri.
+            let allowed_token_uri_schemes = utils::get_allowed_token_uri_schemes();
+            if !allowed_token_uri_schemes.is_empty()
+                && !allowed_token_uri_schemes
+                    .iter()
+                    .any(|scheme| metadata.token_uri.starts_with(scheme.as_str()))
+            {
+                return Err(NFTCoreError::ForbiddenTokenURIScheme);
+            }
             serde_json::to_string_pretty(&metadata)
                 .map_err(|_| NFTCoreError::FailedToJsonifyCEP99Metadata)
         }
@@ -259,6 +277,11 @@ pub(crate) fn validate_metadata(
     }
 }
 
+// A SHA256 digest is 32 bytes, i.e. 64 characters once base16 encoded.
+fn is_sha256_hex_digest(checksum: &str) -> bool {
+    checksum.len() == 64 && checksum.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 pub(crate) fn get_metadata_dictionary_name(metadata_kind: &NFTMetadataKind) -> String {
     let name = match metadata_kind {
         NFTMetadataKind::CEP78 => METADATA_CEP78,
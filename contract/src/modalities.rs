@@ -7,7 +7,7 @@ use alloc::{
 
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
-    CLType, CLTyped,
+    CLType, CLTyped, ContractPackageHash, Key,
 };
 
 use core::convert::TryFrom;
@@ -33,6 +33,44 @@ impl TryFrom<u8> for WhitelistMode {
     }
 }
 
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum OperatorAllowlistMode {
+    Unrestricted = 0,
+    Restricted = 1,
+}
+
+impl TryFrom<u8> for OperatorAllowlistMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OperatorAllowlistMode::Unrestricted),
+            1 => Ok(OperatorAllowlistMode::Restricted),
+            _ => Err(NFTCoreError::InvalidOperatorAllowlistMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq)]
+pub enum ForceTransferMode {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for ForceTransferMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ForceTransferMode::Disabled),
+            1 => Ok(ForceTransferMode::Enabled),
+            _ => Err(NFTCoreError::InvalidForceTransferMode),
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum NFTHolderMode {
@@ -357,6 +395,45 @@ impl ToString for TokenIdentifier {
     }
 }
 
+/// An approval signed off-chain by a token owner and granted on their behalf through the `permit`
+/// entrypoint. Without a `token_id` the spender is approved as an operator for all of the owner's
+/// tokens. The approval expires at `expires_at` like one granted through `approve`. The signed
+/// message is the serialized permit.
+#[derive(PartialEq, Eq, Clone)]
+pub struct Permit {
+    pub contract_package_hash: ContractPackageHash,
+    pub owner: Key,
+    pub spender: Key,
+    pub token_id: Option<TokenIdentifier>,
+    pub nonce: u64,
+    pub deadline: u64,
+    pub expires_at: Option<u64>,
+}
+
+impl ToBytes for Permit {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut bytes = Vec::new();
+        bytes.append(&mut self.contract_package_hash.to_bytes()?);
+        bytes.append(&mut self.owner.to_bytes()?);
+        bytes.append(&mut self.spender.to_bytes()?);
+        bytes.append(&mut self.token_id.to_bytes()?);
+        bytes.append(&mut self.nonce.to_bytes()?);
+        bytes.append(&mut self.deadline.to_bytes()?);
+        bytes.append(&mut self.expires_at.to_bytes()?);
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_package_hash.serialized_length()
+            + self.owner.serialized_length()
+            + self.spender.serialized_length()
+            + self.token_id.serialized_length()
+            + U64_SERIALIZED_LENGTH
+            + U64_SERIALIZED_LENGTH
+            + self.expires_at.serialized_length()
+    }
+}
+
 impl CLTyped for TokenIdentifier {
     fn cl_type() -> casper_types::CLType {
         casper_types::CLType::String
@@ -460,3 +537,35 @@ impl From<u8> for TransferFilterContractResult {
         }
     }
 }
+
+// The arguments the transfer filter contract's `can_transfer` entrypoint is called with.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum TransferFilterInterface {
+    // The source key, target key and token identifier.
+    V1 = 0,
+    // In addition to V1, the caller, its role and the optional data passed to `transfer`.
+    V2 = 1,
+}
+
+impl TryFrom<u8> for TransferFilterInterface {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TransferFilterInterface::V1),
+            1 => Ok(TransferFilterInterface::V2),
+            _ => Err(NFTCoreError::InvalidTransferFilterInterface),
+        }
+    }
+}
+
+// The capacity in which the caller of `transfer` is allowed to transfer the token.
+// Package operators are reported as operators.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum TransferCallerRole {
+    Owner = 0,
+    Approved = 1,
+    Operator = 2,
+}
@@ -1,5 +1,6 @@
 use alloc::{
     borrow::ToOwned,
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
@@ -16,34 +17,42 @@ use casper_contract::{
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
     system::CallStackElement,
-    ApiError, CLTyped, ContractHash, ContractPackageHash, Key, URef,
+    ApiError, CLTyped, ContractHash, ContractPackageHash, Key, PublicKey, Signature, URef,
 };
 
 use crate::{
     constants::{
-        ACL_WHITELIST, ARG_TOKEN_HASH, ARG_TOKEN_ID, BURNT_TOKENS, BURN_MODE, CONTRACT_WHITELIST,
-        HASH_BY_INDEX, HOLDER_MODE, INDEX_BY_HASH, MIGRATION_FLAG, MINTING_MODE,
-        NUMBER_OF_MINTED_TOKENS, OWNED_TOKENS, OWNERSHIP_MODE, PAGE_LIMIT, PAGE_TABLE,
-        PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_OWNERS,
-        TRANSFER_FILTER_CONTRACT, UNMATCHED_HASH_COUNT,
+        ACL_WHITELIST, ALLOWED_TOKEN_URI_SCHEMES, APPROVAL_EXPIRIES, APPROVED, APPROVED_SPENDERS,
+        ARG_EXPIRES_AT, ARG_TOKEN_HASH, ARG_TOKEN_ID, BLOCKLIST, BURNT_TOKENS,
+        BURN_FILTER_CONTRACT, BURN_MODE, CONTRACT_WHITELIST, FORCE_TRANSFER_MODE, HASH_BY_INDEX,
+        HOLDER_MODE, INDEX_BY_HASH, INSTALLER, LEGACY_PAGE_TABLE, LOCKED_TOKENS, MAX_NEW_PAGES,
+        MAX_TOKEN_RANGES, MAX_TRANSFER_FILTER_CONTRACTS, METADATA_SIZE_LIMITS, MIGRATION_FLAG,
+        MINTING_MODE, MINT_FILTER_CONTRACT, NUMBER_OF_MINTED_TOKENS, OPEN_SUPPLY, OPERATORS,
+        OPERATOR_ALLOWLIST, OPERATOR_ALLOWLIST_MODE, OPERATOR_EXPIRIES, OWNED_TOKENS,
+        OWNERSHIP_MODE, PACKAGE_HOLDER_MODE, PAGE_LIMIT, PAGE_SIZE, PAGE_TABLE, PENDING_TRANSFERS,
+        PENDING_TRANSFER_MODE, PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG,
+        TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OPERATORS, TOKEN_OWNERS, TRANSFER_FILTER_CONTRACT,
+        TRANSFER_FILTER_CONTRACTS, TRANSFER_FILTER_INTERFACE, UNMATCHED_HASH_COUNT,
     },
     error::NFTCoreError,
     events::events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        Transfer, VariablesSet,
+        Approval, ApprovalForAll, ApprovalForTokens, ApprovalRevoked, Burn, ForceTransfer,
+        MetadataUpdated, Migration, Mint, PendingTransfer, Revoked, SpenderRevoked, Transfer,
+        TransferFilterContractUpdated, TransferFilterContractsUpdated, VariablesSet,
     },
     modalities::{
-        BurnMode, MetadataRequirement, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, Requirement, TokenIdentifier,
+        BurnMode, ForceTransferMode, MetadataRequirement, MintingMode, NFTHolderMode,
+        NFTIdentifierMode, NFTMetadataKind, OperatorAllowlistMode, OwnerReverseLookupMode,
+        OwnershipMode, Permit, Requirement, TokenIdentifier, TransferFilterInterface,
     },
     utils,
 };
 
-// The size of a given page, it is currently set to 1000
+// The size of a given page unless set otherwise at installation, it is set to 1000
 // to ease the math around addressing newly minted tokens.
-pub const PAGE_SIZE: u64 = 1000;
+pub const DEFAULT_PAGE_SIZE: u64 = 1000;
 
 pub fn upsert_dictionary_value_from_key<T: CLTyped + FromBytes + ToBytes>(
     dictionary_name: &str,
@@ -80,6 +89,59 @@ pub fn get_holder_mode() -> Result<NFTHolderMode, NFTCoreError> {
     .try_into()
 }
 
+pub fn get_operator_allowlist_mode() -> OperatorAllowlistMode {
+    if !named_uref_exists(OPERATOR_ALLOWLIST_MODE) {
+        return OperatorAllowlistMode::Unrestricted;
+    }
+    get_stored_value_with_user_errors::<u8>(
+        OPERATOR_ALLOWLIST_MODE,
+        NFTCoreError::MissingOperatorAllowlistMode,
+        NFTCoreError::InvalidOperatorAllowlistMode,
+    )
+    .try_into()
+    .unwrap_or_revert()
+}
+
+pub fn get_force_transfer_mode() -> ForceTransferMode {
+    if !named_uref_exists(FORCE_TRANSFER_MODE) {
+        return ForceTransferMode::Disabled;
+    }
+    get_stored_value_with_user_errors::<u8>(
+        FORCE_TRANSFER_MODE,
+        NFTCoreError::MissingForceTransferMode,
+        NFTCoreError::InvalidForceTransferMode,
+    )
+    .try_into()
+    .unwrap_or_revert()
+}
+
+// Returns whether an account or contract may be approved as spender or operator, which in the
+// restricted operator allowlist mode requires it to be listed by the installer.
+pub fn is_allowlisted_operator(key: Key) -> bool {
+    match get_operator_allowlist_mode() {
+        OperatorAllowlistMode::Unrestricted => true,
+        OperatorAllowlistMode::Restricted => get_dictionary_value_from_key::<bool>(
+            OPERATOR_ALLOWLIST,
+            &encode_dictionary_item_key(key),
+        )
+        .unwrap_or_default(),
+    }
+}
+
+// Returns whether a caller acting as spender or operator is still allowlisted, either itself or
+// through its contract package, so that removing a key from the allowlist disables its approvals.
+pub fn is_allowlisted_caller(caller: Key, contract_package: Option<Key>) -> bool {
+    is_allowlisted_operator(caller) || contract_package.map_or(false, is_allowlisted_operator)
+}
+
+// Returns whether an account or contract is barred by the installer from holding tokens or being
+// approved. Contracts installed prior to the blocklist block no key.
+pub fn is_blocked(key: Key) -> bool {
+    named_uref_exists(BLOCKLIST)
+        && get_dictionary_value_from_key::<bool>(BLOCKLIST, &encode_dictionary_item_key(key))
+            .unwrap_or_default()
+}
+
 pub fn encode_dictionary_item_key(key: Key) -> String {
     match key {
         Key::Account(account_hash) => account_hash.to_string(),
@@ -98,6 +160,224 @@ pub fn encode_key_and_value<T: CLTyped + ToBytes>(key: &Key, value: &T) -> Strin
     hex::encode(bytes)
 }
 
+// Approvals granted with an expiry lapse once the block time reaches it.
+fn has_expired(expires_at: Option<u64>) -> bool {
+    match expires_at {
+        Some(expires_at) => u64::from(runtime::get_blocktime()) >= expires_at,
+        None => false,
+    }
+}
+
+fn is_approval_expired(expiries_dictionary_name: &str, item_key: &str) -> bool {
+    has_expired(
+        get_dictionary_value_from_key::<Option<u64>>(expiries_dictionary_name, item_key).flatten(),
+    )
+}
+
+pub fn is_operator(owner: &Key, operator: &Key) -> bool {
+    let owner_operator_item_key = encode_key_and_value(owner, operator);
+    get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key).unwrap_or_default()
+        && !is_approval_expired(OPERATOR_EXPIRIES, &owner_operator_item_key)
+}
+
+// Operators approved for all of the owner's tokens also pass, scoped operators only for the
+// token indices they were granted. Hash identified tokens yet to be matched to an index are never
+// covered by a scope.
+pub fn is_token_operator(owner: &Key, operator: &Key, token_identifier: &TokenIdentifier) -> bool {
+    if is_operator(owner, operator) {
+        return true;
+    }
+    let token_index = match token_identifier {
+        TokenIdentifier::Index(token_index) => Some(*token_index),
+        TokenIdentifier::Hash(_) => get_dictionary_value_from_key::<u64>(
+            INDEX_BY_HASH,
+            &token_identifier.get_dictionary_item_key(),
+        ),
+    };
+    let token_index = match token_index {
+        Some(token_index) => token_index,
+        None => return false,
+    };
+    match get_dictionary_value_from_key::<(Vec<(u64, u64)>, Option<u64>)>(
+        TOKEN_OPERATORS,
+        &encode_key_and_value(owner, operator),
+    ) {
+        Some((token_ranges, expires_at)) => {
+            !has_expired(expires_at)
+                && token_ranges
+                    .iter()
+                    .any(|(start, end)| *start <= token_index && token_index < *end)
+        }
+        None => false,
+    }
+}
+
+// Merges token indices and a [start, end) index range into sorted, disjoint ranges, of which
+// there can be at most `MAX_TOKEN_RANGES`.
+pub fn merge_token_ranges(token_ids: Vec<u64>, token_range: Option<(u64, u64)>) -> Vec<(u64, u64)> {
+    let mut token_ranges: Vec<(u64, u64)> = token_ids
+        .into_iter()
+        .map(|token_id| {
+            let end = token_id
+                .checked_add(1)
+                .unwrap_or_revert_with(NFTCoreError::InvalidTokenIds);
+            (token_id, end)
+        })
+        .collect();
+    if let Some((start, end)) = token_range {
+        if start >= end {
+            runtime::revert(NFTCoreError::InvalidTokenRange)
+        }
+        token_ranges.push((start, end));
+    }
+    token_ranges.sort_unstable();
+
+    let mut merged_token_ranges: Vec<(u64, u64)> = Vec::new();
+    for (start, end) in token_ranges {
+        match merged_token_ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged_token_ranges.push((start, end)),
+        }
+    }
+    if merged_token_ranges.len() > MAX_TOKEN_RANGES {
+        runtime::revert(NFTCoreError::InvalidTokenIds)
+    }
+    merged_token_ranges
+}
+
+// Approvals granted before a token could have several spenders live in the legacy `APPROVED`
+// entry, with their expiry keyed by the token alone. Newer approvals are appended to
+// `APPROVED_SPENDERS` and keep one expiry per spender.
+fn get_spender_expiry_item_key(spender: &Key, token_identifier: &TokenIdentifier) -> String {
+    encode_key_and_value(spender, token_identifier)
+}
+
+pub fn get_approved(token_identifier: &TokenIdentifier) -> Vec<Key> {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    let legacy_approved =
+        get_dictionary_value_from_key::<Option<Key>>(APPROVED, &token_identifier_dictionary_key)
+            .flatten()
+            .filter(|_| !is_approval_expired(APPROVAL_EXPIRIES, &token_identifier_dictionary_key));
+    let approved_spenders = get_dictionary_value_from_key::<Vec<Key>>(
+        APPROVED_SPENDERS,
+        &token_identifier_dictionary_key,
+    )
+    .unwrap_or_default()
+    .into_iter()
+    .filter(|spender| {
+        !is_approval_expired(
+            APPROVAL_EXPIRIES,
+            &get_spender_expiry_item_key(spender, token_identifier),
+        )
+    });
+    legacy_approved
+        .into_iter()
+        .chain(approved_spenders)
+        .collect()
+}
+
+pub fn add_approved_spender(
+    token_identifier: &TokenIdentifier,
+    spender: Key,
+    expires_at: Option<u64>,
+) {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    let mut approved_spenders = get_dictionary_value_from_key::<Vec<Key>>(
+        APPROVED_SPENDERS,
+        &token_identifier_dictionary_key,
+    )
+    .unwrap_or_default();
+    // Approving a spender again only refreshes its expiry.
+    if !approved_spenders.contains(&spender) {
+        approved_spenders.push(spender);
+        upsert_dictionary_value_from_key(
+            APPROVED_SPENDERS,
+            &token_identifier_dictionary_key,
+            approved_spenders,
+        );
+    }
+    upsert_dictionary_value_from_key(
+        APPROVAL_EXPIRIES,
+        &get_spender_expiry_item_key(&spender, token_identifier),
+        expires_at,
+    );
+}
+
+// Returns false if the spender was not approved for the token.
+pub fn remove_approved_spender(token_identifier: &TokenIdentifier, spender: Key) -> bool {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    let mut removed = false;
+    if get_dictionary_value_from_key::<Option<Key>>(APPROVED, &token_identifier_dictionary_key)
+        .flatten()
+        == Some(spender)
+    {
+        upsert_dictionary_value_from_key(
+            APPROVED,
+            &token_identifier_dictionary_key,
+            Option::<Key>::None,
+        );
+        removed = true;
+    }
+    let mut approved_spenders = get_dictionary_value_from_key::<Vec<Key>>(
+        APPROVED_SPENDERS,
+        &token_identifier_dictionary_key,
+    )
+    .unwrap_or_default();
+    if let Some(position) = approved_spenders
+        .iter()
+        .position(|approved_spender| *approved_spender == spender)
+    {
+        approved_spenders.remove(position);
+        upsert_dictionary_value_from_key(
+            APPROVED_SPENDERS,
+            &token_identifier_dictionary_key,
+            approved_spenders,
+        );
+        removed = true;
+    }
+    removed
+}
+
+// Only writes the approvals the token actually has, as it is cleared on every transfer.
+pub fn clear_approved_spenders(token_identifier: &TokenIdentifier) {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    if get_dictionary_value_from_key::<Option<Key>>(APPROVED, &token_identifier_dictionary_key)
+        .flatten()
+        .is_some()
+    {
+        upsert_dictionary_value_from_key(
+            APPROVED,
+            &token_identifier_dictionary_key,
+            Option::<Key>::None,
+        );
+    }
+    if get_dictionary_value_from_key::<Vec<Key>>(
+        APPROVED_SPENDERS,
+        &token_identifier_dictionary_key,
+    )
+    .map_or(false, |approved_spenders| !approved_spenders.is_empty())
+    {
+        upsert_dictionary_value_from_key(
+            APPROVED_SPENDERS,
+            &token_identifier_dictionary_key,
+            Vec::<Key>::new(),
+        );
+    }
+}
+
+pub fn get_optional_approval_expiry() -> Option<u64> {
+    let expires_at = get_optional_named_arg_with_user_errors::<u64>(
+        ARG_EXPIRES_AT,
+        NFTCoreError::InvalidApprovalExpiry,
+    );
+    if let Some(expires_at) = expires_at {
+        if expires_at <= u64::from(runtime::get_blocktime()) {
+            runtime::revert(NFTCoreError::ApprovalExpiryInPast)
+        }
+    }
+    expires_at
+}
+
 pub fn get_dictionary_value_from_key<T: CLTyped + FromBytes>(
     dictionary_name: &str,
     key: &str,
@@ -326,6 +606,107 @@ pub fn get_verified_caller() -> Result<Caller, NFTCoreError> {
     }
 }
 
+// Returns whether transfers to unknown holders wait for the target to claim the token. Contracts
+// installed prior to pending transfers transfer tokens right away.
+pub fn get_pending_transfer_mode() -> bool {
+    named_uref_exists(PENDING_TRANSFER_MODE)
+        && get_stored_value_with_user_errors::<bool>(
+            PENDING_TRANSFER_MODE,
+            NFTCoreError::MissingPendingTransferMode,
+            NFTCoreError::InvalidPendingTransferMode,
+        )
+}
+
+pub fn get_pending_transfer(token_identifier: &TokenIdentifier) -> Option<Key> {
+    if !named_uref_exists(PENDING_TRANSFERS) {
+        return None;
+    }
+    get_dictionary_value_from_key::<Option<Key>>(
+        PENDING_TRANSFERS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .flatten()
+}
+
+// Drops the pending transfer of a token, if any. Tokens moved or burnt by any means must not be
+// claimable by the target of an earlier pending transfer.
+pub fn clear_pending_transfer(token_identifier: &TokenIdentifier) {
+    if get_pending_transfer(token_identifier).is_some() {
+        upsert_dictionary_value_from_key(
+            PENDING_TRANSFERS,
+            &token_identifier.get_dictionary_item_key(),
+            Option::<Key>::None,
+        );
+    }
+}
+
+// Returns whether a key is an account that holds or held tokens, or that registered as an owner.
+// Contracts are never known holders, as a token sent to a contract unable to handle it is lost.
+pub fn is_known_holder(key: Key) -> bool {
+    if !matches!(key, Key::Account(_)) {
+        return false;
+    }
+    let item_key = encode_dictionary_item_key(key);
+    get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &item_key).is_some()
+        || (named_uref_exists(PAGE_TABLE)
+            && get_page_table(
+                get_uref(
+                    PAGE_TABLE,
+                    NFTCoreError::MissingPageTableURef,
+                    NFTCoreError::InvalidPageTableURef,
+                ),
+                &item_key,
+            )
+            .is_some())
+}
+
+// Returns the key under which the caller holds tokens. In the package holder mode a contract holds
+// tokens through its package, so that they remain its own across upgrades of the contract.
+pub fn get_holder_key(caller: Key, contract_package: Option<Key>) -> Key {
+    match contract_package {
+        Some(contract_package)
+            if named_uref_exists(PACKAGE_HOLDER_MODE)
+                && get_stored_value_with_user_errors::<bool>(
+                    PACKAGE_HOLDER_MODE,
+                    NFTCoreError::MissingPackageHolderMode,
+                    NFTCoreError::InvalidPackageHolderMode,
+                ) =>
+        {
+            contract_package
+        }
+        _ => caller,
+    }
+}
+
+// Returns the holder key of the caller when it is the installer or the issuer of a token, contracts
+// being matched against the issuer by their holder key.
+pub fn get_installer_or_issuer(token_identifier: &TokenIdentifier) -> Option<Key> {
+    let (caller, contract_package): (Key, Option<Key>) =
+        match get_verified_caller().unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
+        };
+    let installer: Key = get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    )
+    .into();
+    let holder_key = get_holder_key(caller, contract_package);
+    if caller == installer
+        || get_dictionary_value_from_key::<Key>(
+            TOKEN_ISSUERS,
+            &token_identifier.get_dictionary_item_key(),
+        ) == Some(holder_key)
+    {
+        Some(holder_key)
+    } else {
+        None
+    }
+}
+
 pub fn get_token_identifier_from_runtime_args(
     identifier_mode: &NFTIdentifierMode,
 ) -> TokenIdentifier {
@@ -347,6 +728,54 @@ pub fn get_token_identifier_from_runtime_args(
     }
 }
 
+pub fn get_optional_token_identifier_from_runtime_args(
+    identifier_mode: &NFTIdentifierMode,
+) -> Option<TokenIdentifier> {
+    match identifier_mode {
+        NFTIdentifierMode::Ordinal => get_optional_named_arg_with_user_errors::<u64>(
+            ARG_TOKEN_ID,
+            NFTCoreError::InvalidTokenIdentifier,
+        )
+        .map(TokenIdentifier::new_index),
+        NFTIdentifierMode::Hash => get_optional_named_arg_with_user_errors::<String>(
+            ARG_TOKEN_HASH,
+            NFTCoreError::InvalidTokenIdentifier,
+        )
+        .map(TokenIdentifier::new_hash),
+    }
+}
+
+// Returns the package hash of this contract, the last element of the call stack.
+pub fn get_current_contract_package_hash() -> ContractPackageHash {
+    match *runtime::get_call_stack().last().unwrap_or_revert() {
+        CallStackElement::StoredSession {
+            contract_package_hash,
+            ..
+        }
+        | CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => contract_package_hash,
+        CallStackElement::Session { .. } => runtime::revert(NFTCoreError::MissingCep78PackageHash),
+    }
+}
+
+// Verifies the owner's signature of a permit. Kept out of line so that the stack height of the
+// signature arithmetic does not add up with the rest of the calling entrypoint.
+#[inline(never)]
+pub fn is_valid_permit_signature(
+    permit: &Permit,
+    public_key: &PublicKey,
+    signature: &Bytes,
+) -> bool {
+    let signature = match Signature::from_bytes(signature.as_slice()) {
+        Ok((signature, remainder)) if remainder.is_empty() => signature,
+        _ => return false,
+    };
+    let message = permit.to_bytes().unwrap_or_revert();
+    casper_types::crypto::verify(message, &signature, public_key).is_ok()
+}
+
 pub fn get_token_identifiers_from_dictionary(
     identifier_mode: &NFTIdentifierMode,
     owners_item_key: &str,
@@ -391,6 +820,26 @@ pub fn is_token_burned(token_identifier: &TokenIdentifier) -> bool {
         .is_some()
 }
 
+// Tokens of contracts installed prior to token locks have no lock.
+pub fn is_token_locked(token_identifier: &TokenIdentifier) -> bool {
+    named_uref_exists(LOCKED_TOKENS)
+        && get_dictionary_value_from_key::<bool>(
+            LOCKED_TOKENS,
+            &token_identifier.get_dictionary_item_key(),
+        )
+        .unwrap_or_default()
+}
+
+pub fn clear_token_lock(token_identifier: &TokenIdentifier) {
+    if is_token_locked(token_identifier) {
+        upsert_dictionary_value_from_key(
+            LOCKED_TOKENS,
+            &token_identifier.get_dictionary_item_key(),
+            false,
+        );
+    }
+}
+
 pub fn get_transfer_filter_contract() -> Option<ContractHash> {
     if !named_uref_exists(TRANSFER_FILTER_CONTRACT) {
         None
@@ -403,29 +852,277 @@ pub fn get_transfer_filter_contract() -> Option<ContractHash> {
     }
 }
 
+// The chained filter contracts are evaluated in order after the single transfer filter contract.
+pub fn get_transfer_filter_contracts() -> Vec<ContractHash> {
+    let mut filter_contracts: Vec<ContractHash> =
+        get_transfer_filter_contract().into_iter().collect();
+    if named_uref_exists(TRANSFER_FILTER_CONTRACTS) {
+        filter_contracts.extend(get_stored_value_with_user_errors::<Vec<ContractHash>>(
+            TRANSFER_FILTER_CONTRACTS,
+            NFTCoreError::MissingTransferFilterContracts,
+            NFTCoreError::InvalidTransferFilterContracts,
+        ));
+    }
+    filter_contracts
+}
+
+pub fn get_filter_contract_hashes(filter_contract_keys: &[Key]) -> Vec<ContractHash> {
+    if filter_contract_keys.len() > MAX_TRANSFER_FILTER_CONTRACTS {
+        runtime::revert(NFTCoreError::TooManyTransferFilterContracts)
+    }
+    filter_contract_keys
+        .iter()
+        .map(|filter_contract_key| {
+            ContractHash::from(
+                filter_contract_key
+                    .into_hash()
+                    .unwrap_or_revert_with(NFTCoreError::InvalidTransferFilterContracts),
+            )
+        })
+        .collect()
+}
+
+pub fn get_mint_filter_contract() -> Option<ContractHash> {
+    if !named_uref_exists(MINT_FILTER_CONTRACT) {
+        None
+    } else {
+        Some(get_stored_value_with_user_errors::<ContractHash>(
+            MINT_FILTER_CONTRACT,
+            NFTCoreError::MissingMintFilterContract,
+            NFTCoreError::InvalidMintFilterContract,
+        ))
+    }
+}
+
+pub fn get_burn_filter_contract() -> Option<ContractHash> {
+    if !named_uref_exists(BURN_FILTER_CONTRACT) {
+        None
+    } else {
+        Some(get_stored_value_with_user_errors::<ContractHash>(
+            BURN_FILTER_CONTRACT,
+            NFTCoreError::MissingBurnFilterContract,
+            NFTCoreError::InvalidBurnFilterContract,
+        ))
+    }
+}
+
+// Contracts installed before the interface was versioned call their filter with V1 arguments.
+pub fn get_transfer_filter_interface() -> TransferFilterInterface {
+    if !named_uref_exists(TRANSFER_FILTER_INTERFACE) {
+        return TransferFilterInterface::V1;
+    }
+    get_stored_value_with_user_errors::<u8>(
+        TRANSFER_FILTER_INTERFACE,
+        NFTCoreError::MissingTransferFilterInterface,
+        NFTCoreError::InvalidTransferFilterInterface,
+    )
+    .try_into()
+    .unwrap_or_revert()
+}
+
+pub fn get_allowed_token_uri_schemes() -> Vec<String> {
+    if !named_uref_exists(ALLOWED_TOKEN_URI_SCHEMES) {
+        vec![]
+    } else {
+        get_stored_value_with_user_errors::<Vec<String>>(
+            ALLOWED_TOKEN_URI_SCHEMES,
+            NFTCoreError::MissingAllowedTokenURISchemes,
+            NFTCoreError::InvalidAllowedTokenURISchemes,
+        )
+    }
+}
+
+pub fn get_metadata_size_limit(metadata_kind: &NFTMetadataKind) -> Option<u32> {
+    if !named_uref_exists(METADATA_SIZE_LIMITS) {
+        return None;
+    }
+    get_stored_value_with_user_errors::<BTreeMap<NFTMetadataKind, u32>>(
+        METADATA_SIZE_LIMITS,
+        NFTCoreError::MissingMetadataSizeLimits,
+        NFTCoreError::InvalidMetadataSizeLimits,
+    )
+    .get(metadata_kind)
+    .copied()
+}
+
 pub fn max_number_of_pages(total_token_supply: u64) -> u64 {
-    if total_token_supply < PAGE_SIZE {
-        let dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_{}", 0);
+    extend_number_of_pages(0, total_token_supply)
+}
+
+// Creates the page dictionaries needed to track the given total token supply beyond the
+// `current_number_of_pages` already created, and returns the new number of pages. Reverts when
+// more than `MAX_NEW_PAGES` dictionaries would have to be created at once.
+pub fn extend_number_of_pages(current_number_of_pages: u64, total_token_supply: u64) -> u64 {
+    let page_size = get_page_size();
+    let mut number_of_pages = total_token_supply / page_size;
+    // With a page size of say 1000 and a token supply of 1050
+    // total_token_supply / page_size = 1, but we need an additional page
+    // to track the overflow
+    if total_token_supply % page_size != 0 {
+        number_of_pages += 1;
+    }
+    if number_of_pages.saturating_sub(current_number_of_pages) > MAX_NEW_PAGES {
+        runtime::revert(NFTCoreError::InvalidPageSize)
+    }
+    for page_number in current_number_of_pages..number_of_pages {
+        let dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_{page_number}");
         storage::new_dictionary(&dictionary_name)
             .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-        1
+    }
+    number_of_pages.max(current_number_of_pages)
+}
+
+// Contracts installed before the page size was configurable use the default page size.
+pub fn get_page_size() -> u64 {
+    if !named_uref_exists(PAGE_SIZE) {
+        return DEFAULT_PAGE_SIZE;
+    }
+    get_stored_value_with_user_errors::<u64>(
+        PAGE_SIZE,
+        NFTCoreError::MissingPageSize,
+        NFTCoreError::InvalidPageSize,
+    )
+}
+
+pub fn is_open_supply() -> bool {
+    named_uref_exists(OPEN_SUPPLY)
+        && get_stored_value_with_user_errors::<bool>(
+            OPEN_SUPPLY,
+            NFTCoreError::MissingOpenSupply,
+            NFTCoreError::InvalidOpenSupply,
+        )
+}
+
+// In open supply mode a page dictionary is only created once the first token
+// belonging to it is recorded, growing the page limit along with it.
+fn create_page_on_demand(page_table_entry: u64, page_size: u64) {
+    let page_limit_uref = get_uref(
+        PAGE_LIMIT,
+        NFTCoreError::MissingPageLimit,
+        NFTCoreError::InvalidPageLimit,
+    );
+    let page_limit: u64 = read_with_user_errors(
+        page_limit_uref,
+        NFTCoreError::MissingPageLimit,
+        NFTCoreError::InvalidPageLimit,
+    );
+    if page_table_entry >= page_limit {
+        let page_limit =
+            extend_number_of_pages(page_limit, (page_table_entry + 1).saturating_mul(page_size));
+        storage::write(page_limit_uref, page_limit);
+    }
+}
+
+// Pages and page tables are stored as `Bytes` bitsets packing eight slots per byte,
+// the slot at a given index being the bit `index % 8` of the byte `index / 8`.
+pub fn new_bitset(number_of_slots: u64) -> Vec<u8> {
+    vec![0u8; ((number_of_slots + 7) / 8) as usize]
+}
+
+pub fn is_bit_set(bitset: &[u8], index: u64) -> bool {
+    bitset
+        .get((index / 8) as usize)
+        .map(|byte| byte & (1 << (index % 8)) != 0)
+        .unwrap_or(false)
+}
+
+// Returns whether the bit was set prior to the update.
+pub fn set_bit(bitset: &mut [u8], index: u64, value: bool) -> bool {
+    let byte = bitset
+        .get_mut((index / 8) as usize)
+        .unwrap_or_revert_with(NFTCoreError::InvalidPageIndex);
+    let mask = 1u8 << (index % 8);
+    let was_set = *byte & mask != 0;
+    if value {
+        *byte |= mask;
     } else {
-        let max_number_of_pages = total_token_supply / PAGE_SIZE;
-        let overflow = total_token_supply % PAGE_SIZE;
-        for page_number in 0..max_number_of_pages {
-            let dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_{page_number}");
-            storage::new_dictionary(&dictionary_name)
-                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        *byte &= !mask;
+    }
+    was_set
+}
+
+// Page tables are sized to the page limit at the time an owner registers. As the page limit grows,
+// either by raising the total token supply or by creating pages on demand in open supply mode,
+// older page tables are widened to the current page limit on access.
+fn widen_page_table(page_table: &mut Vec<u8>, page_table_entry: u64) {
+    if page_table.len() as u64 * 8 <= page_table_entry {
+        let page_table_width = get_stored_value_with_user_errors::<u64>(
+            PAGE_LIMIT,
+            NFTCoreError::MissingPageLimit,
+            NFTCoreError::InvalidPageLimit,
+        );
+        if page_table_entry >= page_table_width {
+            runtime::revert(NFTCoreError::InvalidPageNumber)
         }
-        // With a page size of say 1000 and a token supply of 1050
-        // max_number_of_pages = 1, but we need an additional page
-        // to track the overflow
-        if overflow == 0 {
-            max_number_of_pages
-        } else {
-            max_number_of_pages + 1
+        page_table.resize(new_bitset(page_table_width).len(), 0u8);
+    }
+}
+
+// Pages and page tables were stored as `Vec<bool>` prior to being packed. As an upgraded contract
+// may hold more pages than a single deploy can rewrite, the pages of an owner are repacked the
+// first time the owner is accessed after the upgrade. Owners recorded in the legacy page table but
+// not yet in the packed one are the owners whose pages are still to be repacked.
+pub fn get_page_table(page_table_uref: URef, item_key: &str) -> Option<Vec<u8>> {
+    if let Some(page_table) = storage::dictionary_get::<Bytes>(page_table_uref, item_key)
+        .unwrap_or_revert()
+        .map(Vec::from)
+    {
+        return Some(page_table);
+    }
+    if !named_uref_exists(LEGACY_PAGE_TABLE) {
+        return None;
+    }
+    get_dictionary_value_from_key::<Vec<bool>>(LEGACY_PAGE_TABLE, item_key)
+        .map(|legacy_page_table| repack_pages(page_table_uref, item_key, &legacy_page_table))
+}
+
+fn repack_pages(page_table_uref: URef, item_key: &str, legacy_page_table: &[bool]) -> Vec<u8> {
+    let page_table_width = get_stored_value_with_user_errors::<u64>(
+        PAGE_LIMIT,
+        NFTCoreError::MissingPageLimit,
+        NFTCoreError::InvalidPageLimit,
+    );
+    let mut page_table = new_bitset(page_table_width.max(legacy_page_table.len() as u64));
+    for (page_table_entry, _) in legacy_page_table
+        .iter()
+        .enumerate()
+        .filter(|(_, is_allocated)| **is_allocated)
+    {
+        let page_uref = get_uref(
+            &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+            NFTCoreError::MissingPageUref,
+            NFTCoreError::InvalidPageUref,
+        );
+        let legacy_page = storage::dictionary_get::<Vec<bool>>(page_uref, item_key)
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(NFTCoreError::MissingPage);
+        let mut page = new_bitset(legacy_page.len() as u64);
+        for (page_address, _) in legacy_page
+            .iter()
+            .enumerate()
+            .filter(|(_, is_owned)| **is_owned)
+        {
+            set_bit(&mut page, page_address as u64, true);
         }
+        storage::dictionary_put(page_uref, item_key, Bytes::from(page));
+        set_bit(&mut page_table, page_table_entry as u64, true);
     }
+    storage::dictionary_put(page_table_uref, item_key, Bytes::from(page_table.clone()));
+    page_table
+}
+
+// Sets the `Vec<bool>` page table aside on upgrade so that pages are repacked owner by owner, see
+// `get_page_table`. Page dictionaries are kept, leaving the receipts held by owners valid.
+pub fn set_aside_legacy_page_table() {
+    let legacy_page_table_uref = get_uref(
+        PAGE_TABLE,
+        NFTCoreError::MissingPageTableURef,
+        NFTCoreError::InvalidPageTableURef,
+    );
+    runtime::remove_key(PAGE_TABLE);
+    runtime::put_key(LEGACY_PAGE_TABLE, legacy_page_table_uref.into());
+    storage::new_dictionary(PAGE_TABLE)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
 }
 
 pub fn insert_hash_id_lookups(
@@ -510,6 +1207,7 @@ pub fn migrate_owned_tokens_in_ordinal_mode() {
         NFTCoreError::MissingPageLimit,
         NFTCoreError::InvalidPageLimit,
     );
+    let page_size = get_page_size();
     let mut searched_token_ids: Vec<u64> = vec![];
     for token_id in 0..current_number_of_minted_tokens {
         if !searched_token_ids.contains(&token_id) {
@@ -527,37 +1225,41 @@ pub fn migrate_owned_tokens_in_ordinal_mode() {
             .unwrap_or_revert();
             for token_identifier in owned_tokens_list.into_iter() {
                 let token_id = token_identifier.get_index().unwrap_or_revert();
-                let page_number = token_id / PAGE_SIZE;
-                let page_index = token_id % PAGE_SIZE;
-                let mut page_record = match storage::dictionary_get::<Vec<bool>>(
-                    page_table_uref,
-                    &token_owner_item_key,
-                )
-                .unwrap_or_revert()
-                {
-                    Some(page_record) => page_record,
-                    None => vec![false; page_table_width as usize],
-                };
+                let page_number = token_id / page_size;
+                let page_index = token_id % page_size;
+                let mut page_record =
+                    match storage::dictionary_get::<Bytes>(page_table_uref, &token_owner_item_key)
+                        .unwrap_or_revert()
+                        .map(Vec::from)
+                    {
+                        Some(page_record) => page_record,
+                        None => new_bitset(page_table_width),
+                    };
+                widen_page_table(&mut page_record, page_number);
                 let page_uref = get_uref(
                     &format!("{PREFIX_PAGE_DICTIONARY}_{page_number}"),
                     NFTCoreError::MissingStorageUref,
                     NFTCoreError::InvalidStorageUref,
                 );
-                let _ = core::mem::replace(&mut page_record[page_number as usize], true);
-                storage::dictionary_put(page_table_uref, &token_owner_item_key, page_record);
+                set_bit(&mut page_record, page_number, true);
+                storage::dictionary_put(
+                    page_table_uref,
+                    &token_owner_item_key,
+                    Bytes::from(page_record),
+                );
                 let mut page =
-                    match storage::dictionary_get::<Vec<bool>>(page_uref, &token_owner_item_key)
+                    match storage::dictionary_get::<Bytes>(page_uref, &token_owner_item_key)
                         .unwrap_or_revert()
+                        .map(Vec::from)
                     {
-                        None => vec![false; PAGE_SIZE as usize],
+                        None => new_bitset(page_size),
                         Some(single_page) => single_page,
                     };
-                let is_already_marked_as_owned =
-                    core::mem::replace(&mut page[page_index as usize], true);
+                let is_already_marked_as_owned = set_bit(&mut page, page_index, true);
                 if is_already_marked_as_owned {
                     runtime::revert(NFTCoreError::InvalidPageIndex)
                 }
-                storage::dictionary_put(page_uref, &token_owner_item_key, page);
+                storage::dictionary_put(page_uref, &token_owner_item_key, Bytes::from(page));
                 searched_token_ids.push(token_id)
             }
         }
@@ -580,13 +1282,9 @@ pub fn should_migrate_token_hashes(token_owner: Key) -> bool {
     );
     // If the owner has registered, then they will have an page table entry
     // but it will contain no bits set.
-    let page_table = storage::dictionary_get::<Vec<bool>>(
-        page_table_uref,
-        &encode_dictionary_item_key(token_owner),
-    )
-    .unwrap_or_revert()
-    .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerFromMigration);
-    if page_table.contains(&true) {
+    let page_table = get_page_table(page_table_uref, &encode_dictionary_item_key(token_owner))
+        .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerFromMigration);
+    if page_table.iter().any(|byte| *byte != 0) {
         return false;
     }
     true
@@ -620,32 +1318,37 @@ pub fn migrate_token_hashes(token_owner: Key) {
         NFTCoreError::InvalidPageLimit,
     );
 
+    let page_size = get_page_size();
+
     for token_identifier in owned_tokens_list.into_iter() {
         let token_address = unmatched_hash_count - 1;
-        let page_table_entry = token_address / PAGE_SIZE;
-        let page_address = token_address % PAGE_SIZE;
-        let mut page_table =
-            match storage::dictionary_get::<Vec<bool>>(page_table_uref, &token_owner_item_key)
-                .unwrap_or_revert()
-            {
-                Some(page_record) => page_record,
-                None => vec![false; page_table_width as usize],
-            };
-        let _ = core::mem::replace(&mut page_table[page_table_entry as usize], true);
-        storage::dictionary_put(page_table_uref, &token_owner_item_key, page_table);
+        let page_table_entry = token_address / page_size;
+        let page_address = token_address % page_size;
+        let mut page_table = match get_page_table(page_table_uref, &token_owner_item_key) {
+            Some(page_record) => page_record,
+            None => new_bitset(page_table_width),
+        };
+        widen_page_table(&mut page_table, page_table_entry);
+        set_bit(&mut page_table, page_table_entry, true);
+        storage::dictionary_put(
+            page_table_uref,
+            &token_owner_item_key,
+            Bytes::from(page_table),
+        );
         let page_uref = get_uref(
             &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
             NFTCoreError::MissingStorageUref,
             NFTCoreError::InvalidStorageUref,
         );
-        let mut page = match storage::dictionary_get::<Vec<bool>>(page_uref, &token_owner_item_key)
+        let mut page = match storage::dictionary_get::<Bytes>(page_uref, &token_owner_item_key)
             .unwrap_or_revert()
+            .map(Vec::from)
         {
             Some(single_page) => single_page,
-            None => vec![false; PAGE_SIZE as usize],
+            None => new_bitset(page_size),
         };
-        let _ = core::mem::replace(&mut page[page_address as usize], true);
-        storage::dictionary_put(page_uref, &token_owner_item_key, page);
+        set_bit(&mut page, page_address, true);
+        storage::dictionary_put(page_uref, &token_owner_item_key, Bytes::from(page));
         insert_hash_id_lookups(unmatched_hash_count - 1, token_identifier);
         unmatched_hash_count -= 1;
     }
@@ -665,7 +1368,8 @@ pub fn get_receipt_name(page_table_entry: u64) -> String {
         NFTCoreError::MissingReceiptName,
         NFTCoreError::InvalidReceiptName,
     );
-    format!("{receipt}_m_{PAGE_SIZE}_p_{page_table_entry}")
+    let page_size = get_page_size();
+    format!("{receipt}_m_{page_size}_p_{page_table_entry}")
 }
 
 pub fn get_reporting_mode() -> OwnerReverseLookupMode {
@@ -686,8 +1390,13 @@ pub fn add_page_entry_and_page_record(
     // there is an explicit page_table;
     // this is the entry in that overall page table which maps to the underlying page
     // upon which this mint's address will exist
-    let page_table_entry = tokens_count / PAGE_SIZE;
-    let page_address = tokens_count % PAGE_SIZE;
+    let page_size = get_page_size();
+    let page_table_entry = tokens_count / page_size;
+    let page_address = tokens_count % page_size;
+
+    if is_open_supply() {
+        create_page_on_demand(page_table_entry, page_size);
+    }
 
     // Update the page entry first
     let page_table_uref = utils::get_uref(
@@ -703,30 +1412,31 @@ pub fn add_page_entry_and_page_record(
         NFTCoreError::InvalidPageUref,
     );
 
-    let mut page_table =
-        match storage::dictionary_get::<Vec<bool>>(page_table_uref, item_key).unwrap_or_revert() {
-            Some(page_table) => page_table,
-            None => runtime::revert(if on_mint {
-                NFTCoreError::UnregisteredOwnerInMint
-            } else {
-                NFTCoreError::UnregisteredOwnerInTransfer
-            }),
-        };
+    let mut page_table = match get_page_table(page_table_uref, item_key) {
+        Some(page_table) => page_table,
+        None => runtime::revert(if on_mint {
+            NFTCoreError::UnregisteredOwnerInMint
+        } else {
+            NFTCoreError::UnregisteredOwnerInTransfer
+        }),
+    };
+    widen_page_table(&mut page_table, page_table_entry);
 
-    let mut page = if !page_table[page_table_entry as usize] {
-        // We mark the page table entry to true to signal the allocation of a page.
-        let _ = core::mem::replace(&mut page_table[page_table_entry as usize], true);
-        storage::dictionary_put(page_table_uref, item_key, page_table);
-        vec![false; PAGE_SIZE as usize]
+    let mut page = if !is_bit_set(&page_table, page_table_entry) {
+        // We set the page table entry bit to signal the allocation of a page.
+        set_bit(&mut page_table, page_table_entry, true);
+        storage::dictionary_put(page_table_uref, item_key, Bytes::from(page_table));
+        new_bitset(page_size)
     } else {
-        storage::dictionary_get::<Vec<bool>>(page_uref, item_key)
+        storage::dictionary_get::<Bytes>(page_uref, item_key)
             .unwrap_or_revert()
+            .map(Vec::from)
             .unwrap_or_revert_with(NFTCoreError::MissingPage)
     };
 
-    let _ = core::mem::replace(&mut page[page_address as usize], true);
+    set_bit(&mut page, page_address, true);
 
-    storage::dictionary_put(page_uref, item_key, page);
+    storage::dictionary_put(page_uref, item_key, Bytes::from(page));
     (page_table_entry, page_uref)
 }
 
@@ -735,8 +1445,19 @@ pub fn update_page_entry_and_page_record(
     old_item_key: &str,
     new_item_key: &str,
 ) -> (u64, URef) {
-    let page_table_entry = tokens_count / PAGE_SIZE;
-    let page_address = tokens_count % PAGE_SIZE;
+    let page_size = get_page_size();
+    let page_table_entry = tokens_count / page_size;
+    let page_address = tokens_count % page_size;
+
+    let page_table_uref = utils::get_uref(
+        PAGE_TABLE,
+        NFTCoreError::MissingPageTableURef,
+        NFTCoreError::InvalidPageTableURef,
+    );
+
+    // Repacks the pages of the source owner if it was not accessed since the upgrade.
+    get_page_table(page_table_uref, old_item_key)
+        .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerInTransfer);
 
     let page_uref = utils::get_uref(
         &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
@@ -744,17 +1465,50 @@ pub fn update_page_entry_and_page_record(
         NFTCoreError::InvalidStorageUref,
     );
 
-    let mut source_page = storage::dictionary_get::<Vec<bool>>(page_uref, old_item_key)
+    let mut source_page = storage::dictionary_get::<Bytes>(page_uref, old_item_key)
         .unwrap_or_revert()
+        .map(Vec::from)
         .unwrap_or_revert_with(NFTCoreError::InvalidPageNumber);
 
-    if !source_page[page_address as usize] {
+    if !set_bit(&mut source_page, page_address, false) {
         runtime::revert(NFTCoreError::InvalidTokenIdentifier)
     }
 
-    let _ = core::mem::replace(&mut source_page[page_address as usize], false);
+    storage::dictionary_put(page_uref, old_item_key, Bytes::from(source_page));
+
+    let mut target_page_table = get_page_table(page_table_uref, new_item_key)
+        .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerInTransfer);
+    widen_page_table(&mut target_page_table, page_table_entry);
+
+    let mut target_page = if !is_bit_set(&target_page_table, page_table_entry) {
+        // Create a new page here
+        set_bit(&mut target_page_table, page_table_entry, true);
+        storage::dictionary_put(
+            page_table_uref,
+            new_item_key,
+            Bytes::from(target_page_table),
+        );
+        new_bitset(page_size)
+    } else {
+        storage::dictionary_get::<Bytes>(page_uref, new_item_key)
+            .unwrap_or_revert()
+            .map(Vec::from)
+            .unwrap_or_revert()
+    };
+
+    set_bit(&mut target_page, page_address, true);
 
-    storage::dictionary_put(page_uref, old_item_key, source_page);
+    storage::dictionary_put(page_uref, new_item_key, Bytes::from(target_page));
+    (page_table_entry, page_uref)
+}
+
+// Clears the bit of a token from its owner's page, releasing the page in the owner's page table
+// once it no longer records any token. Tokens never recorded in a page, as can be the case in
+// `TransfersOnly` reporting mode, are left untouched.
+pub fn remove_page_entry_and_page_record(tokens_count: u64, item_key: &str) {
+    let page_size = get_page_size();
+    let page_table_entry = tokens_count / page_size;
+    let page_address = tokens_count % page_size;
 
     let page_table_uref = utils::get_uref(
         PAGE_TABLE,
@@ -762,25 +1516,32 @@ pub fn update_page_entry_and_page_record(
         NFTCoreError::InvalidPageTableURef,
     );
 
-    let mut target_page_table = storage::dictionary_get::<Vec<bool>>(page_table_uref, new_item_key)
+    let mut page_table = match get_page_table(page_table_uref, item_key) {
+        Some(page_table) if is_bit_set(&page_table, page_table_entry) => page_table,
+        _ => return,
+    };
+
+    let page_uref = utils::get_uref(
+        &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+        NFTCoreError::MissingPageUref,
+        NFTCoreError::InvalidPageUref,
+    );
+
+    let mut page = storage::dictionary_get::<Bytes>(page_uref, item_key)
         .unwrap_or_revert()
-        .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerInTransfer);
+        .map(Vec::from)
+        .unwrap_or_revert_with(NFTCoreError::MissingPage);
 
-    let mut target_page = if !target_page_table[page_table_entry as usize] {
-        // Create a new page here
-        let _ = core::mem::replace(&mut target_page_table[page_table_entry as usize], true);
-        storage::dictionary_put(page_table_uref, new_item_key, target_page_table);
-        vec![false; PAGE_SIZE as usize]
-    } else {
-        storage::dictionary_get::<Vec<bool>>(page_uref, new_item_key)
-            .unwrap_or_revert()
-            .unwrap_or_revert()
-    };
+    if !set_bit(&mut page, page_address, false) {
+        return;
+    }
 
-    let _ = core::mem::replace(&mut target_page[page_address as usize], true);
+    if page.iter().all(|byte| *byte == 0) {
+        set_bit(&mut page_table, page_table_entry, false);
+        storage::dictionary_put(page_table_uref, item_key, Bytes::from(page_table));
+    }
 
-    storage::dictionary_put(page_uref, new_item_key, target_page);
-    (page_table_entry, page_uref)
+    storage::dictionary_put(page_uref, item_key, Bytes::from(page));
 }
 
 pub fn create_metadata_requirements(
@@ -806,18 +1567,39 @@ pub fn create_metadata_requirements(
 }
 
 // Initializes events-releated named keys and records all event schemas.
-pub fn init_events() {
-    let schemas = Schemas::new()
+fn events_schemas() -> Schemas {
+    Schemas::new()
         .with::<Mint>()
         .with::<Burn>()
+        .with::<Revoked>()
         .with::<Approval>()
         .with::<ApprovalRevoked>()
+        .with::<SpenderRevoked>()
         .with::<ApprovalForAll>()
+        .with::<ApprovalForTokens>()
         .with::<Transfer>()
+        .with::<ForceTransfer>()
+        .with::<PendingTransfer>()
         .with::<MetadataUpdated>()
         .with::<VariablesSet>()
-        .with::<Migration>();
-    casper_event_standard::init(schemas);
+        .with::<TransferFilterContractUpdated>()
+        .with::<TransferFilterContractsUpdated>()
+        .with::<Migration>()
+}
+
+pub fn init_events() {
+    casper_event_standard::init(events_schemas());
+}
+
+// Contracts already emitting CES events only know the schemas registered at their installation,
+// so the schemas of the events added since are recorded when they are migrated.
+pub fn update_events_schemas() {
+    let events_schema_uref = get_uref(
+        casper_event_standard::EVENTS_SCHEMA,
+        NFTCoreError::MissingEventsSchema,
+        NFTCoreError::InvalidEventsSchema,
+    );
+    storage::write(events_schema_uref, events_schemas());
 }
 
 pub fn requires_rlo_migration() -> bool {
@@ -16,9 +16,9 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    api_error, contracts::NamedKeys, runtime_args, ApiError, CLType, ContractHash,
-    ContractPackageHash, ContractVersion, EntryPoint, EntryPointAccess, EntryPointType,
-    EntryPoints, Key, Parameter, RuntimeArgs, URef,
+    api_error, contracts::NamedKeys, runtime_args, system::CallStackElement, ApiError, CLType,
+    ContractHash, ContractPackageHash, ContractVersion, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, URef,
 };
 
 const CONTRACT_NAME: &str = "minting_contract_hash";
@@ -34,6 +34,7 @@ const ENTRY_POINT_METADATA: &str = "metadata";
 const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
 const ENTRY_POINT_APPROVE: &str = "approve";
 const ENTRY_POINT_REVOKE: &str = "revoke";
+const ENTRY_POINT_LIST: &str = "list";
 
 const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
 const ARG_TOKEN_OWNER: &str = "token_owner";
@@ -158,6 +159,40 @@ pub extern "C" fn transfer() {
     runtime::put_key(&collection_name, owned_tokens_dictionary_key)
 }
 
+// Called by the NFT contract from approve_and_call, moves the approved token in escrow to this
+// contract as a marketplace listing it would.
+#[no_mangle]
+pub extern "C" fn list() {
+    let call_stack = runtime::get_call_stack();
+    let (nft_contract_hash, listing_contract_hash) =
+        match (call_stack.iter().nth_back(1), call_stack.last()) {
+            (
+                Some(CallStackElement::StoredContract {
+                    contract_hash: nft_contract_hash,
+                    ..
+                }),
+                Some(CallStackElement::StoredContract {
+                    contract_hash: listing_contract_hash,
+                    ..
+                }),
+            ) => (*nft_contract_hash, *listing_contract_hash),
+            _ => runtime::revert(ApiError::InvalidCallerInfoRequest),
+        };
+
+    let token_id = runtime::get_named_arg::<u64>(ARG_TOKEN_ID);
+    let token_owner = runtime::get_named_arg::<Key>(ARG_TOKEN_OWNER);
+
+    runtime::call_contract::<()>(
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => token_owner,
+            ARG_TARGET_KEY => Key::from(listing_contract_hash)
+        },
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn burn() {
     let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
@@ -308,6 +343,16 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let list_entry_point = EntryPoint::new(
+        ENTRY_POINT_LIST,
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_TOKEN_ID, CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(mint_entry_point);
     entry_points.add_entry_point(transfer_entry_point);
@@ -315,6 +360,7 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(revoke_entry_point);
     entry_points.add_entry_point(burn_entry_point);
     entry_points.add_entry_point(metadata_entry_point);
+    entry_points.add_entry_point(list_entry_point);
     entry_points
 }
 
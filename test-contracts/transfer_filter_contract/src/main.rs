@@ -8,12 +8,18 @@ extern crate alloc;
 
 use alloc::{string::ToString, vec};
 
-use casper_contract::contract_api::{
-    runtime::{self, ret},
-    storage,
+use casper_contract::{
+    contract_api::{
+        runtime::{self, ret},
+        storage,
+    },
+    ext_ffi,
 };
 use casper_types::{
-    contracts::NamedKeys, CLType, CLValue, ContractHash, ContractVersion, EntryPoint,
+    api_error, bytesrepr,
+    bytesrepr::{Bytes, FromBytes},
+    contracts::NamedKeys,
+    ApiError, CLType, CLTyped, CLValue, ContractHash, ContractVersion, EntryPoint,
     EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
 };
 
@@ -22,6 +28,11 @@ const CONTRACT_VERSION: &str = "transfer_filter_contract_version";
 const HASH_KEY_NAME: &str = "transfer_filter_contract_package_hash";
 const ACCESS_KEY_NAME: &str = "transfer_filter_contract_access_uref";
 const ARG_FILTER_CONTRACT_RETURN_VALUE: &str = "return_value";
+const ARG_TARGET_KEY: &str = "target_key";
+const ARG_CALLER: &str = "caller";
+const ARG_CALLER_ROLE: &str = "caller_role";
+const ARG_DATA: &str = "data";
+const ARG_TOKEN_OWNER: &str = "token_owner";
 
 fn install_filter_contract() -> (ContractHash, ContractVersion) {
     let can_transfer_entry_point = EntryPoint::new(
@@ -35,6 +46,28 @@ fn install_filter_contract() -> (ContractHash, ContractVersion) {
         EntryPointType::Contract,
     );
 
+    let can_mint_entry_point = EntryPoint::new(
+        "can_mint",
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_CALLER, CLType::Key),
+        ],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let can_burn_entry_point = EntryPoint::new(
+        "can_burn",
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_CALLER, CLType::Key),
+        ],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     let set_return_value = EntryPoint::new(
         "set_return_value",
         vec![Parameter::new(ARG_FILTER_CONTRACT_RETURN_VALUE, CLType::U8)],
@@ -45,6 +78,8 @@ fn install_filter_contract() -> (ContractHash, ContractVersion) {
 
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(can_transfer_entry_point);
+    entry_points.add_entry_point(can_mint_entry_point);
+    entry_points.add_entry_point(can_burn_entry_point);
     entry_points.add_entry_point(set_return_value);
 
     let mut named_keys = NamedKeys::new();
@@ -74,8 +109,57 @@ pub extern "C" fn set_return_value() {
     runtime::put_key(ARG_FILTER_CONTRACT_RETURN_VALUE, Key::from(uref));
 }
 
+fn get_named_arg_size(name: &str) -> Option<usize> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(_) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(e) => runtime::revert(e),
+    }
+}
+
+// Records the received argument under a named key of the same name for inspection.
+fn record_named_arg<T: FromBytes + CLTyped + bytesrepr::ToBytes>(name: &str) {
+    if get_named_arg_size(name).is_some() {
+        let value: T = runtime::get_named_arg(name);
+        runtime::put_key(name, storage::new_uref(value).into());
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn can_transfer() {
+    record_named_arg::<Key>(ARG_TARGET_KEY);
+    record_named_arg::<Key>(ARG_CALLER);
+    record_named_arg::<u8>(ARG_CALLER_ROLE);
+    record_named_arg::<Option<Bytes>>(ARG_DATA);
+
+    ret_stored_return_value();
+}
+
+#[no_mangle]
+pub extern "C" fn can_mint() {
+    record_named_arg::<Key>(ARG_TOKEN_OWNER);
+    record_named_arg::<Key>(ARG_CALLER);
+
+    ret_stored_return_value();
+}
+
+#[no_mangle]
+pub extern "C" fn can_burn() {
+    record_named_arg::<Key>(ARG_TOKEN_OWNER);
+    record_named_arg::<Key>(ARG_CALLER);
+
+    ret_stored_return_value();
+}
+
+fn ret_stored_return_value() {
     let uref = runtime::get_key(ARG_FILTER_CONTRACT_RETURN_VALUE)
         .unwrap()
         .into_uref()
@@ -1,8 +1,8 @@
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, CONTRACT_NAME,
-        MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
-        TEST_PRETTY_721_META_DATA,
+        ACCOUNT_USER_1, ARG_FILTER_CONTRACT_RETURN_VALUE, ARG_NFT_CONTRACT_HASH,
+        ARG_REVERSE_LOOKUP, CONTRACT_NAME, MINTING_CONTRACT_WASM, MINT_SESSION_WASM,
+        NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, TEST_PRETTY_721_META_DATA,
     },
     installer_request_builder::{
         BurnMode, InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode,
@@ -17,15 +17,17 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_types::{runtime_args, Key, RuntimeArgs};
+use casper_types::{bytesrepr::Bytes, runtime_args, Key, RuntimeArgs};
 use contract::{
     constants::{
-        ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNT_TOKENS, BURN_MODE, ENTRY_POINT_BURN,
-        ENTRY_POINT_MINT, ENTRY_POINT_SET_APPROVALL_FOR_ALL, TOKEN_COUNT,
+        ARG_APPROVE_ALL, ARG_CALLER, ARG_COLLECTION_NAME, ARG_OPERATOR, ARG_REASON, ARG_TOKEN_HASH,
+        ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNT_TOKENS, BURN_MODE,
+        ENTRY_POINT_BURN, ENTRY_POINT_MINT, ENTRY_POINT_REVOKE_TOKEN,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, PAGE_TABLE, TOKEN_COUNT,
     },
-    events::events_ces::Burn,
-    modalities::TokenIdentifier,
+    error::NFTCoreError,
+    events::events_ces::{Burn, Revoked},
+    modalities::{TokenIdentifier, TransferFilterContractResult},
 };
 
 fn should_burn_minted_token(reporting: OwnerReverseLookupMode) {
@@ -132,6 +134,25 @@ fn should_burn_minted_token(reporting: OwnerReverseLookupMode) {
     let expected_balance = 0u64;
     assert_eq!(actual_balance, expected_balance);
 
+    if reverse_lookup_enabled {
+        let token_page = support::get_token_page_by_id(
+            &builder,
+            &nft_contract_key,
+            &Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            token_id,
+        );
+        assert!(!token_page[0]);
+
+        // The page no longer records any token and is released.
+        let page_table = support::get_dictionary_value_from_key::<Bytes>(
+            &builder,
+            &nft_contract_key,
+            PAGE_TABLE,
+            &DEFAULT_ACCOUNT_ADDR.to_string(),
+        );
+        assert!(!support::unpack_bits(&page_table)[0]);
+    }
+
     // Expect Burn event.
     let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), token_owner);
     let actual_event: Burn = support::get_event(&builder, &nft_contract_key, 1).unwrap();
@@ -148,6 +169,78 @@ fn should_burn_minted_token_with_transfer_only_reporting() {
     should_burn_minted_token(OwnerReverseLookupMode::TransfersOnly);
 }
 
+#[test]
+fn should_keep_page_allocated_while_it_records_unburnt_tokens() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+    for _ in 0..2 {
+        let mint_session_call = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_session_call).expect_success().commit();
+    }
+
+    let get_page_table = |builder: &InMemoryWasmTestBuilder| {
+        let page_table = support::get_dictionary_value_from_key::<Bytes>(
+            builder,
+            &nft_contract_key,
+            PAGE_TABLE,
+            &DEFAULT_ACCOUNT_ADDR.to_string(),
+        );
+        support::unpack_bits(&page_table)
+    };
+
+    for token_id in 0..2u64 {
+        let burn_request = ExecuteRequestBuilder::contract_call_by_name(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_NAME,
+            ENTRY_POINT_BURN,
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+            },
+        )
+        .build();
+        builder.exec(burn_request).expect_success().commit();
+
+        let token_page = support::get_token_page_by_id(
+            &builder,
+            &nft_contract_key,
+            &Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            token_id,
+        );
+        assert!(!token_page[token_id as usize]);
+
+        if token_id == 0 {
+            assert!(token_page[1]);
+            assert!(get_page_table(&builder)[0]);
+        } else {
+            assert!(!get_page_table(&builder)[0]);
+        }
+    }
+}
+
 #[test]
 fn should_not_burn_previously_burnt_token() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -891,3 +984,287 @@ fn should_burn_token_in_hash_identifier_mode() {
 
     builder.exec(burn_request).expect_success().commit();
 }
+
+#[test]
+fn should_call_burn_filter_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::DenyTransfer,
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_burn_filter_contract(Key::from(filter_contract_hash))
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        192u16,
+        "should not allow burning when the burn filter denies it",
+    );
+
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => TransferFilterContractResult::ProceedTransfer as u8
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    let received_token_owner: Key = support::query_stored_value(
+        &builder,
+        Key::from(filter_contract_hash),
+        vec![ARG_TOKEN_OWNER.to_string()],
+    );
+    assert_eq!(received_token_owner, token_owner_key);
+}
+
+#[test]
+fn should_let_installer_revoke_assigned_token_regardless_of_burn_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Assigned)
+        .with_burn_mode(BurnMode::NonBurnable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+    let token_id = 0u64;
+    let reason = 3u8;
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // The holder of an assigned token can not revoke it.
+    let owner_revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE_TOKEN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_REASON => reason,
+        },
+    )
+    .build();
+
+    builder.exec(owner_revoke_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenRevoker as u16,
+        "token owner should not be able to revoke the token",
+    );
+
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE_TOKEN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_REASON => reason,
+        },
+    )
+    .build();
+
+    builder.exec(revoke_request).expect_success().commit();
+
+    // This will error if token is not registered as burnt.
+    support::get_dictionary_value_from_key::<()>(
+        &builder,
+        &nft_contract_key,
+        BURNT_TOKENS,
+        &token_id.to_string(),
+    );
+
+    let actual_balance = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &token_owner.to_string(),
+    );
+    assert_eq!(actual_balance, 0u64);
+
+    // Expect Revoked event.
+    let expected_event = Revoked::new(
+        token_owner_key,
+        TokenIdentifier::Index(token_id),
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        reason,
+    );
+    let actual_event: Revoked = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(actual_event, expected_event, "Expected Revoked event.");
+}
+
+#[test]
+fn should_call_burn_filter_contract_when_revoking() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::DenyTransfer,
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Assigned)
+        .with_burn_mode(BurnMode::NonBurnable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_burn_filter_contract(Key::from(filter_contract_hash))
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+    let token_id = 0u64;
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let revoke_request = || {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_REVOKE_TOKEN,
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_REASON => 1u8,
+            },
+        )
+        .build()
+    };
+
+    builder.exec(revoke_request()).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        NFTCoreError::BurnFilterContractDenied as u16,
+        "should not revoke a token the burn filter denies burning",
+    );
+
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => TransferFilterContractResult::ProceedTransfer as u8
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    builder.exec(revoke_request()).expect_success().commit();
+
+    // This will error if token is not registered as burnt.
+    support::get_dictionary_value_from_key::<()>(
+        &builder,
+        &nft_contract_key,
+        BURNT_TOKENS,
+        &token_id.to_string(),
+    );
+
+    let received_caller: Key = support::query_stored_value(
+        &builder,
+        Key::from(filter_contract_hash),
+        vec![ARG_CALLER.to_string()],
+    );
+    assert_eq!(received_caller, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+}
@@ -9,10 +9,10 @@ use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
 
 use contract::{
     constants::{
-        ACCESS_KEY_NAME_1_0_0, APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_EVENTS_MODE,
-        ARG_NAMED_KEY_CONVENTION, ARG_OPERATOR, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY,
-        ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNER, BURNT_TOKENS,
-        ENTRY_POINT_APPROVE, ENTRY_POINT_BURN, ENTRY_POINT_REGISTER_OWNER,
+        ACCESS_KEY_NAME_1_0_0, APPROVED_SPENDERS, ARG_APPROVE_ALL, ARG_COLLECTION_NAME,
+        ARG_EVENTS_MODE, ARG_NAMED_KEY_CONVENTION, ARG_OPERATOR, ARG_SOURCE_KEY, ARG_SPENDER,
+        ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNER,
+        BURNT_TOKENS, ENTRY_POINT_APPROVE, ENTRY_POINT_BURN, ENTRY_POINT_REGISTER_OWNER,
         ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA, EVENTS, EVENT_TYPE,
         METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, OPERATOR, OWNER,
         PREFIX_CEP78, PREFIX_HASH_KEY_NAME, RECIPIENT, TOKEN_COUNT, TOKEN_ID,
@@ -543,14 +543,14 @@ fn should_cep47_dictionary_style_approve_event_in_hash_identifier_mode() {
 
     builder.exec(approve_request).expect_success().commit();
 
-    let maybe_approved_spender = get_dictionary_value_from_key::<Option<Key>>(
+    let approved_spenders = get_dictionary_value_from_key::<Vec<Key>>(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_hash,
     );
 
-    assert_eq!(maybe_approved_spender, Some(spender));
+    assert_eq!(approved_spenders, vec![spender]);
 
     let event = get_dictionary_value_from_key::<BTreeMap<String, String>>(
         &builder,
@@ -7,20 +7,25 @@ use casper_types::{runtime_args, CLValue, ContractHash, Key, RuntimeArgs};
 use contract::{
     constants::{
         ACL_WHITELIST, ARG_ALLOW_MINTING, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL,
-        ARG_HOLDER_MODE, ARG_MINTING_MODE, ARG_TOTAL_TOKEN_SUPPLY, ARG_WHITELIST_MODE,
-        ENTRY_POINT_INIT, NUMBER_OF_MINTED_TOKENS,
+        ARG_HOLDER_MODE, ARG_MINTING_MODE, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER,
+        ARG_TOTAL_TOKEN_SUPPLY, ARG_WHITELIST_MODE, ENTRY_POINT_INIT, NUMBER_OF_MINTED_TOKENS,
+        PAGE_LIMIT, PREFIX_PAGE_DICTIONARY,
     },
     events::events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        Transfer, VariablesSet,
+        Approval, ApprovalForAll, ApprovalForTokens, ApprovalRevoked, Burn, ForceTransfer,
+        MetadataUpdated, Migration, Mint, PendingTransfer, Revoked, SpenderRevoked, Transfer,
+        TransferFilterContractUpdated, TransferFilterContractsUpdated, VariablesSet,
     },
 };
 
 use crate::utility::{
-    constants::{CONTRACT_NAME, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL},
+    constants::{
+        ARG_NFT_CONTRACT_HASH, CONTRACT_NAME, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
+        NFT_TEST_COLLECTION, NFT_TEST_SYMBOL, TEST_PRETTY_721_META_DATA,
+    },
     installer_request_builder::{
         InstallerRequestBuilder, MintingMode, NFTHolderMode, NFTIdentifierMode, NFTMetadataKind,
-        OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
+        OperatorAllowlistMode, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
     },
     support::{self, get_dictionary_value_from_key, get_nft_contract_hash},
 };
@@ -112,12 +117,19 @@ fn should_install_contract() {
     let expected_schemas = Schemas::new()
         .with::<Mint>()
         .with::<Burn>()
+        .with::<Revoked>()
         .with::<Approval>()
         .with::<ApprovalRevoked>()
+        .with::<SpenderRevoked>()
         .with::<ApprovalForAll>()
+        .with::<ApprovalForTokens>()
         .with::<Transfer>()
+        .with::<ForceTransfer>()
+        .with::<PendingTransfer>()
         .with::<MetadataUpdated>()
         .with::<VariablesSet>()
+        .with::<TransferFilterContractUpdated>()
+        .with::<TransferFilterContractsUpdated>()
         .with::<Migration>();
     let actual_schemas: Schemas = support::query_stored_value(
         &builder,
@@ -368,6 +380,74 @@ fn should_disallow_installation_with_supply_exceeding_hard_cap() {
     );
 }
 
+#[test]
+fn should_install_with_open_supply_beyond_hard_cap() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(u64::MAX)
+        .with_open_supply(true)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // No page is created up front in open supply mode.
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, 0u64);
+
+    let page_dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_0");
+    let nft_contract = builder
+        .get_contract(nft_contract_hash)
+        .expect("should have nft contract");
+    assert!(!nft_contract
+        .named_keys()
+        .contains_key(&page_dictionary_name));
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, 1u64);
+
+    let nft_contract = builder
+        .get_contract(nft_contract_hash)
+        .expect("should have nft contract");
+    assert!(nft_contract
+        .named_keys()
+        .contains_key(&page_dictionary_name));
+
+    let token_page = support::get_token_page_by_id(
+        &builder,
+        &nft_contract_key,
+        &Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        0u64,
+    );
+    assert!(token_page[0]);
+}
+
 #[test]
 fn should_prevent_installation_with_ownership_and_minting_modality_conflict() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -469,3 +549,18 @@ fn should_allow_installation_with_ownership_transferable_and_owner_reverse_looku
 
     builder.exec(install_request).expect_success().commit();
 }
+
+#[test]
+fn should_prevent_installation_with_operator_allowlist_in_unrestricted_mode() {
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_ownership_mode(OwnershipMode::Transferable)
+            .with_operator_allowlist_mode(OperatorAllowlistMode::Unrestricted)
+            .with_operator_allowlist(vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)]);
+
+    support::assert_expected_invalid_installer_request(
+        install_request_builder,
+        209,
+        "should not allow an operator allowlist when approvals are unrestricted",
+    );
+}
@@ -9,7 +9,8 @@ use contract::{
     constants::{
         ACL_WHITELIST, ARG_COLLECTION_NAME, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA,
         ARG_TOKEN_OWNER, ENTRY_POINT_METADATA, ENTRY_POINT_MINT, ENTRY_POINT_SET_TOKEN_METADATA,
-        METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, TOKEN_OWNERS,
+        HASH_BY_INDEX, INDEX_BY_HASH, METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_NFT721,
+        METADATA_RAW, TOKEN_OWNERS,
     },
     events::events_ces::MetadataUpdated,
     modalities::TokenIdentifier,
@@ -86,7 +87,7 @@ fn should_prevent_update_in_immutable_mode() {
 }
 
 #[test]
-fn should_prevent_install_with_hash_identifier_in_mutable_mode() {
+fn should_require_custom_token_hash_with_hash_identifier_in_mutable_mode() {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder
         .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
@@ -94,16 +95,106 @@ fn should_prevent_install_with_hash_identifier_in_mutable_mode() {
 
     let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
         .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
         .with_nft_metadata_kind(NFTMetadataKind::NFT721)
         .with_identifier_mode(NFTIdentifierMode::Hash)
         .with_metadata_mutability(MetadataMutability::Mutable)
         .build();
 
-    builder.exec(install_request).expect_failure();
+    builder.exec(install_request).expect_success().commit();
 
-    let error = builder.get_error().expect("must fail at installation");
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
 
-    assert_expected_error(error, 102, "Should raise InvalidMetadataMutability(102)")
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(error, 178, "Should raise MissingCustomTokenHash(178)")
+}
+
+#[test]
+fn should_update_metadata_with_custom_token_hash_in_mutable_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_HASH => TOKEN_HASH.to_string(),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        support::get_nft_contract_hash(&builder),
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_HASH => TOKEN_HASH.to_string(),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_token_metadata_request)
+        .expect_success()
+        .commit();
+
+    let updated_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_NFT721,
+        TOKEN_HASH,
+    );
+    assert_eq!(TEST_PRETTY_UPDATED_721_META_DATA, updated_metadata);
+
+    let token_index = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        INDEX_BY_HASH,
+        TOKEN_HASH,
+    );
+    let token_hash = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        HASH_BY_INDEX,
+        &token_index.to_string(),
+    );
+    assert_eq!(token_hash, TOKEN_HASH);
 }
 
 #[test]
@@ -983,3 +1074,195 @@ fn should_not_require_json_schema_when_kind_is_not_custom_validated() {
     should_not_require_json_schema_when_kind_is(NFTMetadataKind::CEP78);
     should_not_require_json_schema_when_kind_is(NFTMetadataKind::NFT721);
 }
+
+fn mint_cep78_metadata(
+    allowed_token_uri_schemes: Vec<String>,
+    token_metadata: String,
+) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .with_allowed_token_uri_schemes(allowed_token_uri_schemes)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => token_metadata,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request);
+    builder
+}
+
+#[test]
+fn should_revert_cep78_metadata_with_invalid_checksum() {
+    for checksum in ["not-a-checksum", "940bffb3f2bba35f", &"z".repeat(64)] {
+        let token_metadata = support::CEP78Metadata::new(
+            "John Doe".to_string(),
+            "https://www.barfoo.com".to_string(),
+            checksum.to_string(),
+        );
+        let mut builder = mint_cep78_metadata(
+            vec![],
+            serde_json::to_string_pretty(&token_metadata).expect("must convert to json metadata"),
+        );
+        builder.expect_failure();
+
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(error, 171, "checksum must be a hex encoded SHA256 digest");
+    }
+}
+
+#[test]
+fn should_revert_cep78_metadata_with_forbidden_token_uri_scheme() {
+    let mut builder = mint_cep78_metadata(
+        vec!["ipfs://".to_string(), "ar://".to_string()],
+        TEST_PRETTY_CEP78_METADATA.to_string(),
+    );
+    builder.expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 172, "token_uri scheme must be allowed");
+}
+
+#[test]
+fn should_mint_cep78_metadata_with_allowed_token_uri_scheme() {
+    let mut builder = mint_cep78_metadata(
+        vec!["ipfs://".to_string(), "https://".to_string()],
+        TEST_PRETTY_CEP78_METADATA.to_string(),
+    );
+    builder.expect_success();
+}
+
+#[test]
+fn should_prevent_install_with_empty_allowed_token_uri_scheme() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .with_allowed_token_uri_schemes(vec!["https://".to_string(), "".to_string()])
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        174,
+        "allowed token_uri schemes must not be empty strings",
+    );
+}
+
+#[test]
+fn should_enforce_metadata_size_limit_on_mint_and_update() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let size_limit = TEST_PRETTY_721_META_DATA.len() as u32;
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_metadata_size_limits(vec![(NFTMetadataKind::NFT721, size_limit)])
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let oversized_metadata = TEST_PRETTY_721_META_DATA.replace("John Doe", "John Doe Jr.");
+
+    let oversized_mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => oversized_metadata.clone(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(oversized_mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 177, "must match MetadataSizeLimitExceeded(177)");
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let oversized_update_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        support::get_nft_contract_hash(&builder),
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => oversized_metadata
+        },
+    )
+    .build();
+
+    builder.exec(oversized_update_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 177, "must match MetadataSizeLimitExceeded(177)");
+}
+
+#[test]
+fn should_prevent_install_with_zero_metadata_size_limit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_metadata_size_limits(vec![(NFTMetadataKind::NFT721, 0u32)])
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 176, "must match InvalidMetadataSizeLimits(176)");
+}
@@ -1,14 +1,15 @@
 use contract::{
     constants::{
-        APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_MINTING_MODE, ARG_OPERATOR,
-        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
+        APPROVED_SPENDERS, ARG_APPROVE_ALL, ARG_CALLER, ARG_COLLECTION_NAME, ARG_MINTING_MODE,
+        ARG_OPERATOR, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
         ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT,
-        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_SET_APPROVALL_FOR_ALL, METADATA_CEP78,
-        METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, NUMBER_OF_MINTED_TOKENS,
-        PAGE_TABLE, RECEIPT_NAME, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
+        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_SET_APPROVALL_FOR_ALL, MAX_NEW_PAGES,
+        MAX_PAGE_SIZE, METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW,
+        MIN_PAGE_SIZE, NUMBER_OF_MINTED_TOKENS, PAGE_LIMIT, PAGE_TABLE, RECEIPT_NAME, TOKEN_COUNT,
+        TOKEN_ISSUERS, TOKEN_OWNERS,
     },
     events::events_ces::{ApprovalForAll, Mint, RevokedForAll},
-    modalities::TokenIdentifier,
+    modalities::{TokenIdentifier, TransferFilterContractResult},
 };
 use serde::{Deserialize, Serialize};
 
@@ -17,16 +18,18 @@ use casper_engine_test_support::{
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
-use casper_types::{account::AccountHash, runtime_args, CLValue, Key, RuntimeArgs};
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, runtime_args, CLValue, Key, RuntimeArgs,
+};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ACCOUNT_USER_2, ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME,
-        ARG_NFT_CONTRACT_HASH, BALANCE_OF_SESSION_WASM, CONTRACT_NAME, GET_APPROVED_WASM,
-        IS_APPROVED_FOR_ALL_WASM, MALFORMED_META_DATA, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
-        NFT_TEST_COLLECTION, OWNER_OF_SESSION_WASM, PAGE_SIZE, TEST_COMPACT_META_DATA,
-        TEST_PRETTY_721_META_DATA, TEST_PRETTY_CEP78_METADATA, TEST_PRETTY_UPDATED_CEP78_METADATA,
-        TRANSFER_SESSION_WASM,
+        ACCOUNT_USER_1, ACCOUNT_USER_2, ARG_FILTER_CONTRACT_RETURN_VALUE,
+        ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH, BALANCE_OF_SESSION_WASM,
+        CONTRACT_NAME, GET_APPROVED_WASM, IS_APPROVED_FOR_ALL_WASM, MALFORMED_META_DATA,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, OWNER_OF_SESSION_WASM,
+        PAGE_SIZE, TEST_COMPACT_META_DATA, TEST_PRETTY_721_META_DATA, TEST_PRETTY_CEP78_METADATA,
+        TEST_PRETTY_UPDATED_CEP78_METADATA, TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
         InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
@@ -288,19 +291,188 @@ fn mint_should_return_dictionary_key_to_callers_owned_tokens() {
         .query(None, account_receipt, &[])
         .expect("must have stored_value")
         .as_cl_value()
-        .map(|page_cl_value| CLValue::into_t::<Vec<bool>>(page_cl_value.clone()))
+        .map(|page_cl_value| CLValue::into_t::<Bytes>(page_cl_value.clone()))
         .unwrap()
         .unwrap();
 
+    // A page packs eight token slots to a byte.
     let expected_page = {
-        let mut page = vec![false; PAGE_SIZE as usize];
-        let _ = std::mem::replace(&mut page[0], true);
-        page
+        let mut page = vec![0u8; (PAGE_SIZE / 8) as usize];
+        page[0] = 0b0000_0001;
+        Bytes::from(page)
     };
 
     assert_eq!(actual_page, expected_page);
 }
 
+#[test]
+fn mint_should_address_tokens_with_a_custom_page_size() {
+    const CUSTOM_PAGE_SIZE: u64 = 10;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_page_size(CUSTOM_PAGE_SIZE)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, 100u64 / CUSTOM_PAGE_SIZE);
+
+    for _ in 0..=CUSTOM_PAGE_SIZE {
+        let mint_session_call = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_session_call).expect_success().commit();
+    }
+
+    let nft_receipt: String =
+        support::query_stored_value(&builder, nft_contract_key, vec![RECEIPT_NAME.to_string()]);
+
+    // The eleventh token is the first entry of the second page.
+    let account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+    let account_receipt = *account
+        .named_keys()
+        .get(&format!("{nft_receipt}_m_{CUSTOM_PAGE_SIZE}_p_{}", 1))
+        .expect("must have receipt");
+
+    let actual_page = builder
+        .query(None, account_receipt, &[])
+        .expect("must have stored_value")
+        .as_cl_value()
+        .map(|page_cl_value| CLValue::into_t::<Bytes>(page_cl_value.clone()))
+        .unwrap()
+        .unwrap();
+
+    let expected_page = Bytes::from(vec![0b0000_0001, 0b0000_0000]);
+    assert_eq!(actual_page, expected_page);
+}
+
+#[test]
+fn should_prevent_installation_with_page_size_out_of_bounds() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    for page_size in [0u64, MIN_PAGE_SIZE - 1, MAX_PAGE_SIZE + 1] {
+        let install_request =
+            InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+                .with_collection_name(NFT_TEST_COLLECTION.to_string())
+                .with_total_token_supply(100u64)
+                .with_ownership_mode(OwnershipMode::Transferable)
+                .with_page_size(page_size)
+                .build();
+
+        builder.exec(install_request).expect_failure();
+
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(error, 183, "should not allow a page size out of bounds");
+    }
+}
+
+#[test]
+fn should_prevent_installation_creating_too_many_pages() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_total_token_supply(MAX_NEW_PAGES * MIN_PAGE_SIZE + 1)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_page_size(MIN_PAGE_SIZE)
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        183,
+        "should not allow a supply taking more pages than can be created at once",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_total_token_supply(MAX_NEW_PAGES * MIN_PAGE_SIZE)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_page_size(MIN_PAGE_SIZE)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, MAX_NEW_PAGES);
+}
+
+#[test]
+fn should_install_with_page_size_at_bounds() {
+    for page_size in [MIN_PAGE_SIZE, MAX_PAGE_SIZE] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let install_request =
+            InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+                .with_collection_name(NFT_TEST_COLLECTION.to_string())
+                .with_total_token_supply(100u64)
+                .with_ownership_mode(OwnershipMode::Transferable)
+                .with_reporting_mode(OwnerReverseLookupMode::Complete)
+                .with_page_size(page_size)
+                .build();
+
+        builder.exec(install_request).expect_success().commit();
+
+        let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+        let page_limit: u64 =
+            support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+        assert_eq!(page_limit, (100u64 + page_size - 1) / page_size);
+
+        let mint_session_call = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_session_call).expect_success().commit();
+    }
+}
+
 #[test]
 fn mint_should_increment_number_of_minted_tokens_by_one_and_add_public_key_to_token_owners() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -1367,14 +1539,14 @@ fn should_approve_in_hash_identifier_mode() {
 
     builder.exec(approve_request).expect_success().commit();
 
-    let maybe_approved_account = support::get_dictionary_value_from_key::<Option<Key>>(
+    let approved_accounts = support::get_dictionary_value_from_key::<Vec<Key>>(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_hash,
     );
 
-    assert_eq!(maybe_approved_account, Some(spender))
+    assert_eq!(approved_accounts, vec![spender])
 }
 
 #[test]
@@ -1471,7 +1643,7 @@ fn should_maintain_page_table_despite_invoking_register_owner() {
 
     builder.exec(mint_session_call).expect_success().commit();
 
-    let actual_page_table = support::get_dictionary_value_from_key::<Vec<bool>>(
+    let actual_page_table = support::get_dictionary_value_from_key::<Bytes>(
         &builder,
         &nft_contract_key,
         PAGE_TABLE,
@@ -1494,7 +1666,7 @@ fn should_maintain_page_table_despite_invoking_register_owner() {
 
     builder.exec(register_call).expect_success().commit();
 
-    let table_post_register = support::get_dictionary_value_from_key::<Vec<bool>>(
+    let table_post_register = support::get_dictionary_value_from_key::<Bytes>(
         &builder,
         &nft_contract_key,
         PAGE_TABLE,
@@ -1965,3 +2137,117 @@ fn should_approve_all_with_flat_gas_cost() {
         second_set_approve_for_all_gas_cost
     )
 }
+
+#[test]
+fn should_prevent_installation_with_a_mint_filter_contract_that_is_not_a_contract_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_mint_filter_contract(Key::Account(AccountHash::new(ACCOUNT_USER_1)))
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        187u16,
+        "should not allow installation with a mint filter contract that is not a contract hash",
+    );
+}
+
+#[test]
+fn should_call_mint_filter_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::DenyTransfer,
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_mint_filter_contract(Key::from(filter_contract_hash))
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        189u16,
+        "should not allow minting when the mint filter denies it",
+    );
+
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => TransferFilterContractResult::ProceedTransfer as u8
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &Key::from(nft_contract_hash),
+        TOKEN_OWNERS,
+        &0u64.to_string(),
+    );
+    assert_eq!(actual_token_owner, token_owner_key);
+
+    let filter_key = Key::from(filter_contract_hash);
+    let received_token_owner: Key =
+        support::query_stored_value(&builder, filter_key, vec![ARG_TOKEN_OWNER.to_string()]);
+    assert_eq!(received_token_owner, token_owner_key);
+    let received_caller: Key =
+        support::query_stored_value(&builder, filter_key, vec![ARG_CALLER.to_string()]);
+    assert_eq!(received_caller, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+}
@@ -2,22 +2,32 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, runtime_args, ContractHash, Key, RuntimeArgs,
+};
 use contract::{
     constants::{
         ACL_PACKAGE_MODE, ALLOW_MINTING, ARG_ACL_PACKAGE_MODE, ARG_ALLOW_MINTING,
-        ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ENTRY_POINT_SET_VARIABLES,
-        OPERATOR_BURN_MODE, PACKAGE_OPERATOR_MODE,
+        ARG_COLLECTION_NAME, ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_SOURCE_KEY,
+        ARG_TARGET_KEY, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY,
+        ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_CONTRACTS, ENTRY_POINT_MINT,
+        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_SET_VARIABLES, ENTRY_POINT_TRANSFER, MAX_NEW_PAGES,
+        MAX_TOTAL_TOKEN_SUPPLY, MAX_TRANSFER_FILTER_CONTRACTS, MIN_PAGE_SIZE, OPERATOR_BURN_MODE,
+        PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PAGE_TABLE, PREFIX_PAGE_DICTIONARY, RECEIPT_NAME,
+        TOTAL_TOKEN_SUPPLY, TRANSFER_FILTER_CONTRACT,
     },
     error::NFTCoreError,
-    events::events_ces::VariablesSet,
+    events::events_ces::{TransferFilterContractUpdated, VariablesSet},
+    modalities::TransferFilterContractResult,
 };
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, CONTRACT_NAME, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
+        ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH, CONTRACT_NAME,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
+        TEST_PRETTY_721_META_DATA, TRANSFER_SESSION_WASM,
     },
-    installer_request_builder::{InstallerRequestBuilder, OwnerReverseLookupMode},
+    installer_request_builder::{InstallerRequestBuilder, OwnerReverseLookupMode, OwnershipMode},
     support::{self, assert_expected_error, get_nft_contract_hash},
 };
 
@@ -284,3 +294,549 @@ fn installer_should_be_able_to_toggle_operator_burn_mode() {
     let actual_event: VariablesSet = support::get_event(&builder, &nft_contract_key, 0).unwrap();
     assert_eq!(actual_event, expected_event, "Expected VariablesSet event.");
 }
+
+#[test]
+fn installer_should_be_able_to_raise_total_token_supply() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_page_size(MIN_PAGE_SIZE)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = || {
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build()
+    };
+
+    builder.exec(mint_request()).expect_success().commit();
+    builder.exec(mint_request()).expect_failure();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::TokenSupplyDepleted as u16,
+        "supply should be depleted before being raised",
+    );
+
+    // The receiver registers while the page table spans a single page, its page table has to be
+    // widened once it receives a token from one of the pages added by raising the supply.
+    let token_receiver = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_receiver_key = Key::Account(token_receiver);
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_receiver_key
+        },
+    )
+    .build();
+
+    builder.exec(register_request).expect_success().commit();
+
+    let total_token_supply = 10 * MIN_PAGE_SIZE;
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_TOTAL_TOKEN_SUPPLY => total_token_supply },
+    )
+    .build();
+
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    let actual_total_token_supply: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![TOTAL_TOKEN_SUPPLY.to_string()],
+    );
+    assert_eq!(actual_total_token_supply, total_token_supply);
+
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, 10u64);
+
+    let nft_contract = builder
+        .get_contract(nft_contract_hash)
+        .expect("should have nft contract");
+    for page_number in 0..page_limit {
+        assert!(nft_contract
+            .named_keys()
+            .contains_key(&format!("{PREFIX_PAGE_DICTIONARY}_{page_number}")));
+    }
+
+    // Mint up to the first token of the ninth page, beyond the first byte of the page tables.
+    let token_id = 8 * MIN_PAGE_SIZE;
+    for _ in 1..=token_id {
+        builder.exec(mint_request()).expect_success().commit();
+    }
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let token_receiver_page_table: Bytes = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        PAGE_TABLE,
+        &token_receiver.to_string(),
+    );
+    assert_eq!(
+        token_receiver_page_table,
+        Bytes::from(vec![0b0000_0000, 0b0000_0001])
+    );
+
+    let token_receiver_page: Bytes = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        &format!("{PREFIX_PAGE_DICTIONARY}_8"),
+        &token_receiver.to_string(),
+    );
+    assert_eq!(token_receiver_page, Bytes::from(vec![0b0000_0001]));
+
+    let nft_receipt: String =
+        support::query_stored_value(&builder, nft_contract_key, vec![RECEIPT_NAME.to_string()]);
+    let page_uref = *builder
+        .get_contract(nft_contract_hash)
+        .expect("should have nft contract")
+        .named_keys()
+        .get(&format!("{PREFIX_PAGE_DICTIONARY}_8"))
+        .and_then(Key::as_uref)
+        .expect("must have page uref");
+    let account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+    let actual_receipt = *account
+        .named_keys()
+        .get(&format!("{nft_receipt}_m_{MIN_PAGE_SIZE}_p_8"))
+        .expect("must have receipt");
+    assert_eq!(
+        actual_receipt,
+        Key::dictionary(page_uref, DEFAULT_ACCOUNT_ADDR.to_string().as_bytes())
+    );
+}
+
+#[test]
+fn should_not_raise_total_token_supply_creating_too_many_pages() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(MIN_PAGE_SIZE)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_page_size(MIN_PAGE_SIZE)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let set_total_token_supply_request = |total_token_supply: u64| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_SET_VARIABLES,
+            runtime_args! {
+                ARG_TOTAL_TOKEN_SUPPLY => total_token_supply
+            },
+        )
+        .build()
+    };
+
+    // One page more than can be created at once on top of the existing page.
+    builder
+        .exec(set_total_token_supply_request(
+            (MAX_NEW_PAGES + 1) * MIN_PAGE_SIZE + 1,
+        ))
+        .expect_failure();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidPageSize as u16,
+        "should not create more pages than can be created at once",
+    );
+
+    builder
+        .exec(set_total_token_supply_request(
+            (MAX_NEW_PAGES + 1) * MIN_PAGE_SIZE,
+        ))
+        .expect_success()
+        .commit();
+
+    let page_limit: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_hash.into(),
+        vec![PAGE_LIMIT.to_string()],
+    );
+    assert_eq!(page_limit, MAX_NEW_PAGES + 1);
+}
+
+#[test]
+fn should_not_be_able_to_lower_or_exceed_total_token_supply() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    for (total_token_supply, expected_error) in [
+        (9u64, NFTCoreError::CannotDecreaseTotalTokenSupply),
+        (
+            MAX_TOTAL_TOKEN_SUPPLY + 1,
+            NFTCoreError::ExceededMaxTotalSupply,
+        ),
+    ] {
+        let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_SET_VARIABLES,
+            runtime_args! { ARG_TOTAL_TOKEN_SUPPLY => total_token_supply },
+        )
+        .build();
+
+        builder.exec(set_variables_request).expect_failure();
+
+        let error = builder.get_error().expect("should have an error");
+        assert_expected_error(
+            error,
+            expected_error as u16,
+            "total token supply should only be raised up to the maximum",
+        );
+    }
+}
+
+#[test]
+fn should_be_able_to_raise_total_token_supply_beyond_maximum_in_open_supply() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(10u64)
+        .with_open_supply(true)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let total_token_supply = MAX_TOTAL_TOKEN_SUPPLY * 10;
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_TOTAL_TOKEN_SUPPLY => total_token_supply },
+    )
+    .build();
+
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    let actual_total_token_supply: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![TOTAL_TOKEN_SUPPLY.to_string()],
+    );
+    assert_eq!(actual_total_token_supply, total_token_supply);
+
+    // Pages are still only created on demand.
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, 0u64);
+}
+
+#[test]
+fn installer_should_be_able_to_set_and_remove_transfer_filter_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let transfer_filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::DenyTransfer,
+    );
+    let transfer_filter_contract_key = Key::from(transfer_filter_contract_hash);
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_TRANSFER_FILTER_CONTRACT => Some(transfer_filter_contract_key) },
+    )
+    .build();
+
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    let actual_transfer_filter_contract: ContractHash = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![TRANSFER_FILTER_CONTRACT.to_string()],
+    );
+    assert_eq!(
+        actual_transfer_filter_contract,
+        transfer_filter_contract_hash
+    );
+
+    let expected_event = TransferFilterContractUpdated::new(Some(transfer_filter_contract_key));
+    let actual_event: TransferFilterContractUpdated =
+        support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected TransferFilterContractUpdated event."
+    );
+
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let transfer_args = runtime_args! {
+        ARG_TOKEN_ID => 0u64,
+        ARG_SOURCE_KEY => token_owner_key,
+        ARG_TARGET_KEY => Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+    };
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args.clone(),
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::TransferFilterContractDenied as u16,
+        "should not allow transfer denied by the newly set transfer filter",
+    );
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_TRANSFER_FILTER_CONTRACT => Option::<Key>::None },
+    )
+    .build();
+
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    let nft_contract = builder
+        .get_contract(nft_contract_hash)
+        .expect("must have nft contract");
+    assert!(!nft_contract
+        .named_keys()
+        .contains_key(TRANSFER_FILTER_CONTRACT));
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args,
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_set_transfer_filter_contract_without_transferable_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let transfer_filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::ProceedTransfer,
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Minter)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_TRANSFER_FILTER_CONTRACT => Some(Key::from(transfer_filter_contract_hash))
+        },
+    )
+    .build();
+
+    builder.exec(set_variables_request).expect_failure();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::TransferFilterContractNeedsTransferableMode as u16,
+        "should not set a transfer filter contract on non transferable tokens",
+    );
+}
+
+#[test]
+fn should_not_chain_more_than_the_maximum_number_of_transfer_filter_contracts() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let transfer_filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::ProceedTransfer,
+    );
+    let transfer_filter_contracts =
+        vec![Key::from(transfer_filter_contract_hash); MAX_TRANSFER_FILTER_CONTRACTS + 1];
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_transfer_filter_contracts(transfer_filter_contracts.clone())
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::TooManyTransferFilterContracts as u16,
+        "should not install with too many chained transfer filter contracts",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_transfer_filter_contracts(
+            transfer_filter_contracts[..MAX_TRANSFER_FILTER_CONTRACTS].to_vec(),
+        )
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_TRANSFER_FILTER_CONTRACTS => transfer_filter_contracts
+        },
+    )
+    .build();
+
+    builder.exec(set_variables_request).expect_failure();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::TooManyTransferFilterContracts as u16,
+        "should not chain too many transfer filter contracts through set_variables",
+    );
+}
@@ -4,35 +4,54 @@ use casper_engine_test_support::{
 };
 use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
 use casper_types::{
-    account::AccountHash, runtime_args, Key, PublicKey, RuntimeArgs, SecretKey, U512,
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, Key, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 use contract::{
     constants::{
-        ACL_WHITELIST, APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR,
-        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT,
+        ACL_WHITELIST, APPROVED_SPENDERS, ARG_APPROVE_ALL, ARG_BLOCKLIST, ARG_CALLER,
+        ARG_CALLER_ROLE, ARG_COLLECTION_NAME, ARG_DATA, ARG_DEADLINE, ARG_ENTRY_POINT,
+        ARG_EXPIRES_AT, ARG_NONCE, ARG_OPERATOR, ARG_OPERATOR_ALLOWLIST, ARG_OWNER_PUBLIC_KEY,
+        ARG_SIGNATURE, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
+        ARG_TOKEN_IDS, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOKEN_RANGE,
+        ARG_TRANSFER_FILTER_CONTRACTS, ENTRY_POINT_APPROVE, ENTRY_POINT_APPROVE_AND_CALL,
+        ENTRY_POINT_BURN, ENTRY_POINT_CANCEL, ENTRY_POINT_CANCEL_PERMIT, ENTRY_POINT_CLAIM,
+        ENTRY_POINT_FORCE_TRANSFER, ENTRY_POINT_LOCK_TOKEN, ENTRY_POINT_MINT, ENTRY_POINT_PERMIT,
         ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
-        ENTRY_POINT_TRANSFER, PAGE_TABLE, TOKEN_COUNT, TOKEN_OWNERS,
+        ENTRY_POINT_SET_APPROVAL_FOR_TOKENS, ENTRY_POINT_SET_VARIABLES, ENTRY_POINT_TRANSFER,
+        ENTRY_POINT_UNLOCK_TOKEN, LOCKED_TOKENS, MAX_TOKEN_RANGES, PAGE_TABLE, PENDING_TRANSFERS,
+        PERMIT_NONCES, TOKEN_COUNT, TOKEN_OWNERS,
+    },
+    error::NFTCoreError,
+    events::events_ces::{
+        Approval, ApprovalRevoked, ForceTransfer, PendingTransfer, SpenderRevoked, Transfer,
+        TransferFilterContractsUpdated,
+    },
+    modalities::{
+        Permit, TokenIdentifier, TransferCallerRole, TransferFilterContractResult,
+        TransferFilterInterface,
     },
-    events::events_ces::{Approval, ApprovalRevoked, Transfer},
-    modalities::{TokenIdentifier, TransferFilterContractResult},
 };
 
 use crate::utility::{
     constants::{
         ACCOUNT_USER_1, ACCOUNT_USER_2, ACCOUNT_USER_3, ARG_FILTER_CONTRACT_RETURN_VALUE,
-        ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP,
+        ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP,
+        GET_APPROVED_SPENDERS_WASM, GET_APPROVED_WASM, IS_APPROVED_FOR_ALL_WASM,
         MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
         NFT_TEST_SYMBOL, TEST_PRETTY_721_META_DATA, TRANSFER_FILTER_CONTRACT_WASM,
         TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
-        InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
+        ForceTransferMode, InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode,
+        NFTIdentifierMode, NFTMetadataKind, OperatorAllowlistMode, OwnerReverseLookupMode,
+        OwnershipMode, WhitelistMode,
     },
     support::{
-        self, assert_expected_error, create_funded_dummy_account, get_dictionary_value_from_key,
-        get_minting_contract_hash, get_minting_contract_package_hash, get_nft_contract_hash,
+        self, assert_expected_error, create_dummy_key_pair, create_funded_dummy_account,
+        get_dictionary_value_from_key, get_minting_contract_hash,
+        get_minting_contract_package_hash, get_nft_contract_hash,
         get_transfer_filter_contract_hash,
     },
 };
@@ -315,14 +334,14 @@ fn approve_token_for_transfer_should_add_entry_to_approved_dictionary(
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_key: Option<Key> = support::get_dictionary_value_from_key(
+    let actual_approved_key: Vec<Key> = support::get_dictionary_value_from_key(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_id.to_string(),
     );
 
-    assert_eq!(actual_approved_key, Some(spender_key));
+    assert_eq!(actual_approved_key, vec![spender_key]);
 
     // Expect Approval event.
     let expected_event = Approval::new(owner_key, spender_key, TokenIdentifier::Index(token_id));
@@ -419,14 +438,14 @@ fn revoke_token_for_transfer_should_remove_entry_to_approved_dictionary(
 
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_key: Option<Key> = support::get_dictionary_value_from_key(
+    let actual_approved_key: Vec<Key> = support::get_dictionary_value_from_key(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_id.to_string(),
     );
 
-    assert_eq!(actual_approved_key, Some(spender_key));
+    assert_eq!(actual_approved_key, vec![spender_key]);
 
     let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
         *DEFAULT_ACCOUNT_ADDR,
@@ -439,14 +458,14 @@ fn revoke_token_for_transfer_should_remove_entry_to_approved_dictionary(
     .build();
     builder.exec(revoke_request).expect_success().commit();
 
-    let actual_approved_key: Option<Key> = support::get_dictionary_value_from_key(
+    let actual_approved_key: Vec<Key> = support::get_dictionary_value_from_key(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_id.to_string(),
     );
 
-    assert_eq!(actual_approved_key, None);
+    assert_eq!(actual_approved_key, Vec::<Key>::new());
 
     // Expect ApprovalRevoked event.
     let expected_event = ApprovalRevoked::new(owner_key, TokenIdentifier::Index(token_id));
@@ -605,10 +624,14 @@ fn should_be_able_to_transfer_token(
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
     assert_eq!(
         actual_approved_account, expected_approved_account,
         "approved account should have been set in dictionary when approved"
@@ -646,11 +669,16 @@ fn should_be_able_to_transfer_token(
     .build();
     builder.exec(transfer_request).expect_success().commit();
 
-    let actual_approved_account_hash: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account_hash: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
     assert_eq!(
-        actual_approved_account_hash, None,
+        actual_approved_account_hash,
+        Vec::<Key>::new(),
         "approved account should be set to none after a transfer"
     );
 }
@@ -729,10 +757,14 @@ fn should_dissallow_same_approved_account_to_transfer_token_twice() {
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
 
     assert_eq!(
         actual_approved_account, expected_approved_account,
@@ -876,10 +908,14 @@ fn should_disallow_to_transfer_token_using_revoked_hash(
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
     assert_eq!(
         actual_approved_account, expected_approved_account,
         "approved account should have been set in dictionary when approved"
@@ -938,11 +974,16 @@ fn should_disallow_to_transfer_token_using_revoked_hash(
         "transfer from revoked account must raise InvalidTokenOwner",
     );
 
-    let actual_approved_account_hash: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account_hash: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
     assert_eq!(
-        actual_approved_account_hash, None,
+        actual_approved_account_hash,
+        Vec::<Key>::new(),
         "approved account should be unset after revoke and a failed transfer"
     );
 }
@@ -1020,10 +1061,14 @@ fn should_be_able_to_approve_with_deprecated_operator_argument() {
     builder.exec(approve_request).expect_success().commit();
 
     let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
     assert_eq!(
         actual_approved_account, expected_approved_account,
         "approved account should have been set in dictionary when approved"
@@ -1482,14 +1527,14 @@ fn transfer_should_correctly_track_page_table_entries() {
 
     builder.exec(transfer_request).expect_success().commit();
 
-    let account_user_1_page_table = support::get_dictionary_value_from_key::<Vec<bool>>(
+    let account_user_1_page_table = support::get_dictionary_value_from_key::<Bytes>(
         &builder,
         &nft_contract_key,
         PAGE_TABLE,
         &AccountHash::new(ACCOUNT_USER_1).to_string(),
     );
 
-    assert!(account_user_1_page_table[0])
+    assert!(support::unpack_bits(&account_user_1_page_table)[0])
 }
 
 #[test]
@@ -2033,6 +2078,325 @@ fn check_transfers_with_transfer_filter_contract_modes() {
     builder.exec(transfer_request).expect_success().commit();
 }
 
+#[test]
+fn should_pass_target_key_to_v1_transfer_filter() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let transfer_filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::ProceedTransfer,
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_transfer_filter_contract(Key::from(transfer_filter_contract_hash))
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_receiver_key = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let filter_key = Key::from(transfer_filter_contract_hash);
+    let received_target_key: Key =
+        support::query_stored_value(&builder, filter_key, vec![ARG_TARGET_KEY.to_string()]);
+    assert_eq!(received_target_key, token_receiver_key);
+
+    let filter_named_keys = builder
+        .get_contract(transfer_filter_contract_hash)
+        .expect("must have filter contract")
+        .named_keys()
+        .clone();
+    assert!(!filter_named_keys.contains_key(ARG_CALLER));
+    assert!(!filter_named_keys.contains_key(ARG_DATA));
+}
+
+#[test]
+fn should_pass_caller_role_and_data_to_v2_transfer_filter() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let transfer_filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::ProceedTransfer,
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_transfer_filter_contract(Key::from(transfer_filter_contract_hash))
+        .with_transfer_filter_interface(TransferFilterInterface::V2 as u8)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    for _ in 0..2 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => token_owner_key,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    let token_receiver_key = Key::Account(AccountHash::new(ACCOUNT_USER_2));
+    let data = Bytes::from(vec![1u8, 2, 3]);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+            ARG_DATA => data.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let filter_key = Key::from(transfer_filter_contract_hash);
+    let received_target_key: Key =
+        support::query_stored_value(&builder, filter_key, vec![ARG_TARGET_KEY.to_string()]);
+    assert_eq!(received_target_key, token_receiver_key);
+    let received_caller: Key =
+        support::query_stored_value(&builder, filter_key, vec![ARG_CALLER.to_string()]);
+    assert_eq!(received_caller, token_owner_key);
+    let received_caller_role: u8 =
+        support::query_stored_value(&builder, filter_key, vec![ARG_CALLER_ROLE.to_string()]);
+    assert_eq!(received_caller_role, TransferCallerRole::Owner as u8);
+    let received_data: Option<Bytes> =
+        support::query_stored_value(&builder, filter_key, vec![ARG_DATA.to_string()]);
+    assert_eq!(received_data, Some(data));
+
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let spender_key = Key::Account(spender);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SPENDER => spender_key
+        },
+    )
+    .build();
+
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let received_caller: Key =
+        support::query_stored_value(&builder, filter_key, vec![ARG_CALLER.to_string()]);
+    assert_eq!(received_caller, spender_key);
+    let received_caller_role: u8 =
+        support::query_stored_value(&builder, filter_key, vec![ARG_CALLER_ROLE.to_string()]);
+    assert_eq!(received_caller_role, TransferCallerRole::Approved as u8);
+    let received_data: Option<Bytes> =
+        support::query_stored_value(&builder, filter_key, vec![ARG_DATA.to_string()]);
+    assert_eq!(received_data, None);
+}
+
+#[test]
+fn should_evaluate_chained_transfer_filter_contracts_in_order() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let proceeding_filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::ProceedTransfer,
+    );
+    let denying_filter_contract_hash = support::install_transfer_filter_contract(
+        &mut builder,
+        TransferFilterContractResult::DenyTransfer,
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_transfer_filter_contracts(vec![
+            Key::from(proceeding_filter_contract_hash),
+            Key::from(denying_filter_contract_hash),
+        ])
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_receiver_key = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+    let transfer_args = runtime_args! {
+        ARG_TOKEN_ID => 0u64,
+        ARG_SOURCE_KEY => token_owner_key,
+        ARG_TARGET_KEY => token_receiver_key,
+    };
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args.clone(),
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        159u16,
+        "should not allow transfer when any chained transfer filter denies it",
+    );
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_TRANSFER_FILTER_CONTRACTS => vec![Key::from(proceeding_filter_contract_hash)]
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    let expected_event =
+        TransferFilterContractsUpdated::new(vec![Key::from(proceeding_filter_contract_hash)]);
+    let actual_event: TransferFilterContractsUpdated =
+        support::get_event(&builder, &nft_contract_key, 2).unwrap();
+    assert_eq!(actual_event, expected_event);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args,
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let received_target_key: Key = support::query_stored_value(
+        &builder,
+        Key::from(proceeding_filter_contract_hash),
+        vec![ARG_TARGET_KEY.to_string()],
+    );
+    assert_eq!(received_target_key, token_receiver_key);
+}
+
+#[test]
+fn should_prevent_installation_with_invalid_transfer_filter_interface() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_transfer_filter_interface(2u8)
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        185u16,
+        "should not allow installation with an invalid transfer filter interface",
+    );
+}
+
 #[test]
 fn should_disallow_transfer_from_contract_with_package_operator_mode_without_operator() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -2344,7 +2708,7 @@ fn should_allow_transfer_from_contract_with_package_operator_mode_with_operator(
 }
 
 #[test]
-fn should_disallow_package_operator_to_approve_without_package_operator_mode() {
+fn should_allow_transfer_from_contract_with_approved_package_without_package_operator_mode() {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder
         .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
@@ -2402,15 +2766,231 @@ fn should_disallow_package_operator_to_approve_without_package_operator_mode() {
 
     builder.exec(register_request).expect_success().commit();
 
-    let minting_contract_hash = get_minting_contract_hash(&builder);
+    let token_id = 0u64;
     let minting_contract_package_hash = get_minting_contract_package_hash(&builder);
 
-    let set_approve_for_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
         *DEFAULT_ACCOUNT_ADDR,
         nft_contract_hash,
-        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        ENTRY_POINT_APPROVE,
         runtime_args! {
-            ARG_APPROVE_ALL => true,
+            ARG_TOKEN_ID => token_id,
+            ARG_SPENDER => Key::from(minting_contract_package_hash)
+        },
+    )
+    .build();
+
+    builder.exec(approve_request).expect_success().commit();
+
+    let minting_contract_hash = get_minting_contract_hash(&builder);
+
+    let approved_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        minting_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder
+        .exec(approved_transfer_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner = support::get_dictionary_value_from_key::<Key>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    )
+    .into_account()
+    .unwrap();
+
+    assert_eq!(actual_token_owner, token_receiver);
+}
+
+#[test]
+fn should_let_upgraded_contract_transfer_tokens_held_by_its_package_with_package_holder_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let minting_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINTING_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(minting_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_holder_mode(NFTHolderMode::Mixed)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_package_holder_mode(true)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let minting_contract_package_key = Key::from(get_minting_contract_package_hash(&builder));
+
+    let token_receiver = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_receiver_key = Key::Account(token_receiver);
+
+    for token_owner_key in [minting_contract_package_key, token_receiver_key] {
+        let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_REGISTER_OWNER,
+            runtime_args! {
+                ARG_TOKEN_OWNER => token_owner_key
+            },
+        )
+        .build();
+
+        builder.exec(register_request).expect_success().commit();
+    }
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => minting_contract_package_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // Upgrading the minting contract adds a new contract version to its package.
+    let minting_contract_upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINTING_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    let previous_minting_contract_hash = get_minting_contract_hash(&builder);
+
+    builder
+        .exec(minting_contract_upgrade_request)
+        .expect_success()
+        .commit();
+
+    let minting_contract_hash = get_minting_contract_hash(&builder);
+    assert_ne!(minting_contract_hash, previous_minting_contract_hash);
+
+    let token_id = 0u64;
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        minting_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => minting_contract_package_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let actual_token_owner = support::get_dictionary_value_from_key::<Key>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    )
+    .into_account()
+    .unwrap();
+
+    assert_eq!(actual_token_owner, token_receiver);
+}
+
+#[test]
+fn should_disallow_package_operator_to_approve_without_package_operator_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let minting_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINTING_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(minting_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_holder_mode(NFTHolderMode::Mixed)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA ,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let token_receiver = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_receiver_key = Key::Account(token_receiver);
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_receiver_key
+        },
+    )
+    .build();
+
+    builder.exec(register_request).expect_success().commit();
+
+    let minting_contract_hash = get_minting_contract_hash(&builder);
+    let minting_contract_package_hash = get_minting_contract_package_hash(&builder);
+
+    let set_approve_for_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
             ARG_OPERATOR => Key::from(minting_contract_package_hash)
         },
     )
@@ -2886,3 +3466,2221 @@ fn should_allow_package_operator_to_revoke_with_package_operator_mode() {
 
     assert_eq!(actual_token_owner, *DEFAULT_ACCOUNT_ADDR);
 }
+
+#[test]
+fn should_not_allow_transfer_with_expired_operator_approval() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let operator = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let operator_key = Key::Account(operator);
+    let expires_at = 10_000u64;
+
+    let set_approval_for_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
+            ARG_OPERATOR => operator_key,
+            ARG_EXPIRES_AT => expires_at,
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_approval_for_all_request)
+        .expect_success()
+        .commit();
+
+    let is_approved_for_all_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        IS_APPROVED_FOR_ALL_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_KEY_NAME => ARG_OPERATOR.to_string(),
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_OPERATOR => operator_key,
+        },
+    )
+    .with_block_time(expires_at)
+    .build();
+
+    builder
+        .exec(is_approved_for_all_request)
+        .expect_success()
+        .commit();
+
+    let is_operator: bool =
+        support::query_stored_value(&builder, token_owner_key, vec![ARG_OPERATOR.to_string()]);
+    assert!(!is_operator, "expired operator should not be approved");
+
+    let transfer_args = runtime_args! {
+        ARG_TOKEN_ID => 0u64,
+        ARG_SOURCE_KEY => token_owner_key,
+        ARG_TARGET_KEY => operator_key,
+    };
+
+    let expired_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args.clone(),
+    )
+    .with_block_time(expires_at)
+    .build();
+
+    builder.exec(expired_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "expired operator should not be able to transfer",
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args,
+    )
+    .with_block_time(expires_at - 1)
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_allow_transfer_with_expired_token_approval() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let spender_key = Key::Account(spender);
+    let block_time = 5_000u64;
+
+    let past_expiry_approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => spender_key,
+            ARG_EXPIRES_AT => block_time,
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+
+    builder.exec(past_expiry_approve_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::ApprovalExpiryInPast as u16,
+        "should not approve with an expiry already passed",
+    );
+
+    let expires_at = block_time + 1_000;
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => spender_key,
+            ARG_EXPIRES_AT => expires_at,
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_args = runtime_args! {
+        ARG_TOKEN_ID => 0u64,
+        ARG_SOURCE_KEY => token_owner_key,
+        ARG_TARGET_KEY => spender_key,
+    };
+
+    let expired_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args.clone(),
+    )
+    .with_block_time(expires_at)
+    .build();
+
+    builder.exec(expired_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "expired spender should not be able to transfer",
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args,
+    )
+    .with_block_time(expires_at - 1)
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+#[test]
+fn should_allow_scoped_operator_to_transfer_only_tokens_in_scope() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(3u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    for _ in 0..3 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => token_owner_key,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    let operator = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let operator_key = Key::Account(operator);
+
+    let set_approval_for_tokens_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVAL_FOR_TOKENS,
+        runtime_args! {
+            ARG_OPERATOR => operator_key,
+            ARG_TOKEN_IDS => vec![0u64],
+            ARG_TOKEN_RANGE => (2u64, 3u64),
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_approval_for_tokens_request)
+        .expect_success()
+        .commit();
+
+    let out_of_scope_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => operator_key,
+        },
+    )
+    .build();
+
+    builder.exec(out_of_scope_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "scoped operator should not be able to transfer a token out of scope",
+    );
+
+    for token_id in [0u64, 2u64] {
+        let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+            operator,
+            nft_contract_hash,
+            ENTRY_POINT_TRANSFER,
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_SOURCE_KEY => token_owner_key,
+                ARG_TARGET_KEY => operator_key,
+            },
+        )
+        .build();
+
+        builder.exec(transfer_request).expect_success().commit();
+
+        let actual_token_owner: Key = get_dictionary_value_from_key(
+            &builder,
+            &nft_contract_hash.into(),
+            TOKEN_OWNERS,
+            &token_id.to_string(),
+        );
+        assert_eq!(actual_token_owner, operator_key);
+    }
+
+    // Passing neither token_ids nor token_range revokes the scoped approval.
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVAL_FOR_TOKENS,
+        runtime_args! {
+            ARG_OPERATOR => operator_key,
+        },
+    )
+    .build();
+
+    builder.exec(revoke_request).expect_success().commit();
+
+    let revoked_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => operator_key,
+        },
+    )
+    .build();
+
+    builder.exec(revoked_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "revoked scoped operator should not be able to transfer",
+    );
+}
+
+#[test]
+fn should_prevent_scoped_operator_approval_with_empty_token_range() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let operator_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_1),
+    ));
+
+    let set_approval_for_tokens_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVAL_FOR_TOKENS,
+        runtime_args! {
+            ARG_OPERATOR => operator_key,
+            ARG_TOKEN_RANGE => (1u64, 1u64),
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_approval_for_tokens_request)
+        .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenRange as u16,
+        "should not allow an empty token range",
+    );
+}
+
+#[test]
+fn should_prevent_scoped_operator_approval_with_too_many_token_ranges() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let operator_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_1),
+    ));
+
+    // Every other index, so that none of them merge into a single range.
+    let token_ids: Vec<u64> = (0..=MAX_TOKEN_RANGES as u64).map(|i| i * 2).collect();
+
+    let set_approval_for_tokens_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVAL_FOR_TOKENS,
+        runtime_args! {
+            ARG_OPERATOR => operator_key,
+            ARG_TOKEN_IDS => token_ids,
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_approval_for_tokens_request)
+        .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenIds as u16,
+        "should not allow more than the maximum number of token ranges",
+    );
+
+    // Adjacent indices merge into a single range.
+    let token_ids: Vec<u64> = (0..=MAX_TOKEN_RANGES as u64).collect();
+
+    let set_approval_for_tokens_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVAL_FOR_TOKENS,
+        runtime_args! {
+            ARG_OPERATOR => operator_key,
+            ARG_TOKEN_IDS => token_ids,
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_approval_for_tokens_request)
+        .expect_success()
+        .commit();
+}
+
+fn sign_permit(permit: &Permit, account_string: [u8; 32]) -> Bytes {
+    let (secret_key, public_key) = create_dummy_key_pair(account_string);
+    let message = permit.to_bytes().expect("must serialize permit");
+    let signature = casper_types::crypto::sign(message, &secret_key, &public_key);
+    Bytes::from(signature.to_bytes().expect("must serialize signature"))
+}
+
+#[test]
+fn should_approve_spender_with_signed_permit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_1),
+    ));
+    let (_, token_owner_public_key) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let spender_key = Key::Account(spender);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let permit = Permit {
+        contract_package_hash: builder
+            .get_contract(nft_contract_hash)
+            .expect("must have nft contract")
+            .contract_package_hash(),
+        owner: token_owner_key,
+        spender: spender_key,
+        token_id: Some(TokenIdentifier::new_index(0)),
+        nonce: 0,
+        deadline: 10_000,
+        expires_at: None,
+    };
+    let permit_args = runtime_args! {
+        ARG_OWNER_PUBLIC_KEY => token_owner_public_key,
+        ARG_SPENDER => spender_key,
+        ARG_TOKEN_ID => 0u64,
+        ARG_NONCE => permit.nonce,
+        ARG_DEADLINE => permit.deadline,
+        ARG_SIGNATURE => sign_permit(&permit, ACCOUNT_USER_1),
+    };
+
+    // The spender submits the permit, the owner does not pay for an approve deploy.
+    let permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_PERMIT,
+        permit_args.clone(),
+    )
+    .build();
+
+    builder.exec(permit_request).expect_success().commit();
+
+    let approved: Vec<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_hash.into(), APPROVED_SPENDERS, "0");
+    assert_eq!(approved, vec![spender_key]);
+
+    let replayed_permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_PERMIT,
+        permit_args,
+    )
+    .build();
+
+    builder.exec(replayed_permit_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidPermitNonce as u16,
+        "a permit should not be replayed",
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => spender_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+#[test]
+fn should_reject_expired_or_forged_permit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let (_, token_owner_public_key) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let token_owner_key = Key::Account(token_owner_public_key.to_account_hash());
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let spender_key = Key::Account(spender);
+
+    let permit = Permit {
+        contract_package_hash: builder
+            .get_contract(nft_contract_hash)
+            .expect("must have nft contract")
+            .contract_package_hash(),
+        owner: token_owner_key,
+        spender: spender_key,
+        token_id: None,
+        nonce: 0,
+        deadline: 10_000,
+        expires_at: None,
+    };
+    let signature = sign_permit(&permit, ACCOUNT_USER_1);
+
+    let expired_permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_PERMIT,
+        runtime_args! {
+            ARG_OWNER_PUBLIC_KEY => token_owner_public_key.clone(),
+            ARG_SPENDER => spender_key,
+            ARG_NONCE => permit.nonce,
+            ARG_DEADLINE => permit.deadline,
+            ARG_SIGNATURE => signature.clone(),
+        },
+    )
+    .with_block_time(permit.deadline + 1)
+    .build();
+
+    builder.exec(expired_permit_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::PermitExpired as u16,
+        "a permit should not be granted after its deadline",
+    );
+
+    // The signature does not cover an approval of another spender.
+    let forged_permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_PERMIT,
+        runtime_args! {
+            ARG_OWNER_PUBLIC_KEY => token_owner_public_key,
+            ARG_SPENDER => Key::Account(AccountHash::new(ACCOUNT_USER_3)),
+            ARG_NONCE => permit.nonce,
+            ARG_DEADLINE => permit.deadline,
+            ARG_SIGNATURE => signature,
+        },
+    )
+    .build();
+
+    builder.exec(forged_permit_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidPermitSignature as u16,
+        "a permit should only approve the signed spender",
+    );
+}
+
+#[test]
+fn should_expire_approval_granted_by_permit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    // Permits are verified alike for owners holding Secp256k1 keys.
+    let token_owner_secret_key = SecretKey::secp256k1_from_bytes([5u8; 32]).unwrap();
+    let token_owner_public_key = PublicKey::from(&token_owner_secret_key);
+    let token_owner_key = Key::Account(token_owner_public_key.to_account_hash());
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let spender_key = Key::Account(spender);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let block_time = 5_000u64;
+    let expires_at = block_time + 1_000;
+
+    let permit = Permit {
+        contract_package_hash: builder
+            .get_contract(nft_contract_hash)
+            .expect("must have nft contract")
+            .contract_package_hash(),
+        owner: token_owner_key,
+        spender: spender_key,
+        token_id: Some(TokenIdentifier::new_index(0)),
+        nonce: 0,
+        deadline: 10_000,
+        expires_at: Some(expires_at),
+    };
+    let message = permit.to_bytes().expect("must serialize permit");
+    let signature =
+        casper_types::crypto::sign(message, &token_owner_secret_key, &token_owner_public_key);
+    let signature = Bytes::from(signature.to_bytes().expect("must serialize signature"));
+
+    // The signature covers the expiry, the spender cannot extend it.
+    let extended_permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_PERMIT,
+        runtime_args! {
+            ARG_OWNER_PUBLIC_KEY => token_owner_public_key.clone(),
+            ARG_SPENDER => spender_key,
+            ARG_TOKEN_ID => 0u64,
+            ARG_NONCE => permit.nonce,
+            ARG_DEADLINE => permit.deadline,
+            ARG_EXPIRES_AT => expires_at + 1,
+            ARG_SIGNATURE => signature.clone(),
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+
+    builder.exec(extended_permit_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidPermitSignature as u16,
+        "a permit should only approve the signed expiry",
+    );
+
+    let permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_PERMIT,
+        runtime_args! {
+            ARG_OWNER_PUBLIC_KEY => token_owner_public_key,
+            ARG_SPENDER => spender_key,
+            ARG_TOKEN_ID => 0u64,
+            ARG_NONCE => permit.nonce,
+            ARG_DEADLINE => permit.deadline,
+            ARG_EXPIRES_AT => expires_at,
+            ARG_SIGNATURE => signature,
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+
+    builder.exec(permit_request).expect_success().commit();
+
+    let transfer_args = runtime_args! {
+        ARG_TOKEN_ID => 0u64,
+        ARG_SOURCE_KEY => token_owner_key,
+        ARG_TARGET_KEY => spender_key,
+    };
+
+    let expired_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args.clone(),
+    )
+    .with_block_time(expires_at)
+    .build();
+
+    builder.exec(expired_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "expired spender should not be able to transfer",
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_args,
+    )
+    .with_block_time(expires_at - 1)
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+#[test]
+fn should_cancel_unused_permit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let (_, token_owner_public_key) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let spender_key = Key::Account(spender);
+
+    let permit = Permit {
+        contract_package_hash: builder
+            .get_contract(nft_contract_hash)
+            .expect("must have nft contract")
+            .contract_package_hash(),
+        owner: Key::Account(token_owner),
+        spender: spender_key,
+        token_id: None,
+        nonce: 0,
+        deadline: 10_000,
+        expires_at: None,
+    };
+
+    // The owner changes its mind before the spender submits the permit.
+    let cancel_permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_CANCEL_PERMIT,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(cancel_permit_request)
+        .expect_success()
+        .commit();
+
+    let actual_nonce: u64 = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        PERMIT_NONCES,
+        &token_owner.to_string(),
+    );
+    assert_eq!(actual_nonce, 1u64);
+
+    let permit_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_PERMIT,
+        runtime_args! {
+            ARG_OWNER_PUBLIC_KEY => token_owner_public_key,
+            ARG_SPENDER => spender_key,
+            ARG_NONCE => permit.nonce,
+            ARG_DEADLINE => permit.deadline,
+            ARG_SIGNATURE => sign_permit(&permit, ACCOUNT_USER_1),
+        },
+    )
+    .build();
+
+    builder.exec(permit_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidPermitNonce as u16,
+        "a cancelled permit should not be granted",
+    );
+}
+
+#[test]
+fn should_only_approve_and_honor_allowlisted_operators() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let listed_operator = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let listed_operator_key = Key::Account(listed_operator);
+    let unlisted_operator = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let unlisted_operator_key = Key::Account(unlisted_operator);
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_operator_allowlist_mode(OperatorAllowlistMode::Restricted)
+        .with_operator_allowlist(vec![listed_operator_key])
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let unlisted_approval_for_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
+            ARG_OPERATOR => unlisted_operator_key,
+        },
+    )
+    .build();
+
+    builder
+        .exec(unlisted_approval_for_all_request)
+        .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::UnlistedOperator as u16,
+        "should not approve an operator missing from the allowlist",
+    );
+
+    let unlisted_approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => unlisted_operator_key,
+        },
+    )
+    .build();
+
+    builder.exec(unlisted_approve_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::UnlistedOperator as u16,
+        "should not approve a spender missing from the allowlist",
+    );
+
+    let listed_approval_for_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
+            ARG_OPERATOR => listed_operator_key,
+        },
+    )
+    .build();
+
+    builder
+        .exec(listed_approval_for_all_request)
+        .expect_success()
+        .commit();
+
+    // The installer replaces the allowlist.
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_OPERATOR_ALLOWLIST => vec![unlisted_operator_key],
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    // The approval of the operator removed from the allowlist can no longer be used.
+    let delisted_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        listed_operator,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => listed_operator_key,
+        },
+    )
+    .build();
+
+    builder.exec(delisted_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::UnlistedOperator as u16,
+        "should not let an operator removed from the allowlist transfer",
+    );
+
+    let delisted_burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        listed_operator,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(delisted_burn_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::UnlistedOperator as u16,
+        "should not let an operator removed from the allowlist burn",
+    );
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => unlisted_operator_key,
+        },
+    )
+    .build();
+
+    builder.exec(approve_request).expect_success().commit();
+
+    let actual_approved_key: Vec<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_hash.into(), APPROVED_SPENDERS, "0");
+    assert_eq!(actual_approved_key, vec![unlisted_operator_key]);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        unlisted_operator,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => unlisted_operator_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+#[test]
+fn should_prevent_transfer_of_locked_token_until_unlocked() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_receiver_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_2),
+    ));
+    let token_id = 0u64;
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(token_owner),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // Only the installer or the issuer of the token can lock it.
+    let owner_lock_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_LOCK_TOKEN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(owner_lock_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenLocker as u16,
+        "token owner should not be able to lock the token",
+    );
+
+    let lock_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_LOCK_TOKEN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(lock_request).expect_success().commit();
+
+    let is_locked: bool = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        LOCKED_TOKENS,
+        &token_id.to_string(),
+    );
+    assert!(is_locked);
+
+    let transfer_runtime_arguments = runtime_args! {
+        ARG_TOKEN_ID => token_id,
+        ARG_SOURCE_KEY => Key::Account(token_owner),
+        ARG_TARGET_KEY => token_receiver_key,
+    };
+
+    let locked_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_runtime_arguments.clone(),
+    )
+    .build();
+
+    builder.exec(locked_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::LockedToken as u16,
+        "locked token should not be transferable",
+    );
+
+    let unlock_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_UNLOCK_TOKEN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(unlock_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_runtime_arguments,
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(actual_token_owner, token_receiver_key);
+}
+
+#[test]
+fn should_not_force_transfer_without_force_transfer_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(token_owner),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TARGET_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+    .build();
+
+    builder.exec(force_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidForceTransferMode as u16,
+        "should not force transfer unless enabled at installation",
+    );
+}
+
+#[test]
+fn should_let_installer_force_transfer_assigned_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Assigned)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_force_transfer_mode(ForceTransferMode::Enabled)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+    let token_receiver_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_2),
+    ));
+    let token_id = 0u64;
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let force_transfer_runtime_arguments = runtime_args! {
+        ARG_TOKEN_ID => token_id,
+        ARG_TARGET_KEY => token_receiver_key,
+    };
+
+    // Only the installer can force transfers, not even the token owner.
+    let owner_force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        force_transfer_runtime_arguments.clone(),
+    )
+    .build();
+
+    builder.exec(owner_force_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidAccount as u16,
+        "token owner should not be able to force transfer",
+    );
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        force_transfer_runtime_arguments,
+    )
+    .build();
+
+    builder
+        .exec(force_transfer_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(actual_token_owner, token_receiver_key);
+
+    let previous_owner_balance: u64 = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &token_owner.to_string(),
+    );
+    assert_eq!(previous_owner_balance, 0u64);
+
+    let receiver_balance: u64 = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &token_receiver_key.into_account().unwrap().to_string(),
+    );
+    assert_eq!(receiver_balance, 1u64);
+
+    // Expect ForceTransfer event.
+    let expected_event = ForceTransfer::new(
+        token_owner_key,
+        token_receiver_key,
+        TokenIdentifier::Index(token_id),
+    );
+    let actual_event: ForceTransfer = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected ForceTransfer event."
+    );
+}
+
+#[test]
+fn should_prevent_blocked_keys_from_receiving_tokens_and_approvals() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let blocked_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_2),
+    ));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_blocklist(vec![blocked_key])
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_id = 0u64;
+
+    let blocked_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => blocked_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(blocked_mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::BlockedKey as u16,
+        "should not mint to a blocked key",
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(token_owner),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let blocked_approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SPENDER => blocked_key,
+        },
+    )
+    .build();
+
+    builder.exec(blocked_approve_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::BlockedKey as u16,
+        "should not approve a blocked key",
+    );
+
+    let blocked_operator_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
+            ARG_OPERATOR => blocked_key,
+        },
+    )
+    .build();
+
+    builder.exec(blocked_operator_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::BlockedKey as u16,
+        "should not approve a blocked key as operator",
+    );
+
+    let transfer_runtime_arguments = runtime_args! {
+        ARG_TOKEN_ID => token_id,
+        ARG_SOURCE_KEY => Key::Account(token_owner),
+        ARG_TARGET_KEY => blocked_key,
+    };
+
+    let blocked_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_runtime_arguments.clone(),
+    )
+    .build();
+
+    builder.exec(blocked_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::BlockedKey as u16,
+        "should not transfer to a blocked key",
+    );
+
+    // The installer replaces the blocklist through set_variables.
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_BLOCKLIST => Vec::<Key>::new(),
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        transfer_runtime_arguments,
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(actual_token_owner, blocked_key);
+}
+
+#[test]
+fn should_park_transfers_to_unknown_holders_until_claimed_or_cancelled() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_pending_transfer_mode(true)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let token_receiver_key = Key::Account(token_receiver);
+    let other_account = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_3));
+
+    for _ in 0..2 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => token_owner_key,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    // The receiver never held a token, so the transfer waits for it to claim the token.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, "0");
+    assert_eq!(actual_token_owner, token_owner_key);
+
+    let pending_transfer: Option<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, PENDING_TRANSFERS, "0");
+    assert_eq!(pending_transfer, Some(token_receiver_key));
+
+    let retransfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => Key::Account(other_account),
+        },
+    )
+    .build();
+
+    builder.exec(retransfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::PendingTransfer as u16,
+        "should not transfer a token with a pending transfer",
+    );
+
+    let other_claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        other_account,
+        nft_contract_hash,
+        ENTRY_POINT_CLAIM,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(other_claim_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidClaimant as u16,
+        "only the target of the pending transfer should claim the token",
+    );
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_receiver,
+        nft_contract_hash,
+        ENTRY_POINT_CLAIM,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(claim_request).expect_success().commit();
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, "0");
+    assert_eq!(actual_token_owner, token_receiver_key);
+
+    let receiver_balance: u64 = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &token_receiver.to_string(),
+    );
+    assert_eq!(receiver_balance, 1u64);
+
+    // The owner cancels a transfer sent to the wrong account.
+    let mistaken_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => Key::Account(other_account),
+        },
+    )
+    .build();
+
+    builder
+        .exec(mistaken_transfer_request)
+        .expect_success()
+        .commit();
+
+    let cancel_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_CANCEL,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+        },
+    )
+    .build();
+
+    builder.exec(cancel_request).expect_success().commit();
+
+    let pending_transfer: Option<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, PENDING_TRANSFERS, "1");
+    assert_eq!(pending_transfer, None);
+
+    // The receiver now holds a token and receives transfers right away.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, "1");
+    assert_eq!(actual_token_owner, token_receiver_key);
+}
+
+#[test]
+fn should_only_park_transfers_made_by_the_token_owner() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_pending_transfer_mode(true)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let spender_key = Key::Account(spender);
+    let token_receiver_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_3),
+    ));
+
+    for token_id in 0..2u64 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => token_owner_key,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            token_owner,
+            nft_contract_hash,
+            ENTRY_POINT_APPROVE,
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_SPENDER => spender_key,
+            },
+        )
+        .build();
+
+        builder.exec(approve_request).expect_success().commit();
+    }
+
+    // Transfers made by approved spenders are never parked, even to unknown holders.
+    let spender_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder
+        .exec(spender_transfer_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, "0");
+    assert_eq!(actual_token_owner, token_receiver_key);
+
+    let expected_event = Transfer::new(
+        token_owner_key,
+        Some(spender_key),
+        token_receiver_key,
+        TokenIdentifier::Index(0),
+    );
+    let actual_event: Transfer = support::get_event(&builder, &nft_contract_key, 4).unwrap();
+    assert_eq!(actual_event, expected_event, "Expected Transfer event.");
+
+    // The owner's own transfer to a contract is parked and clears the approvals of the token.
+    let contract_key = Key::Hash([4u8; 32]);
+    let owner_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => contract_key,
+        },
+    )
+    .build();
+
+    builder
+        .exec(owner_transfer_request)
+        .expect_success()
+        .commit();
+
+    let pending_transfer: Option<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, PENDING_TRANSFERS, "1");
+    assert_eq!(pending_transfer, Some(contract_key));
+
+    let approved_spenders: Vec<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED_SPENDERS, "1");
+    assert!(approved_spenders.is_empty());
+
+    let expected_event =
+        PendingTransfer::new(token_owner_key, contract_key, TokenIdentifier::Index(1));
+    let actual_event: PendingTransfer = support::get_event(&builder, &nft_contract_key, 5).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected PendingTransfer event."
+    );
+}
+
+#[test]
+fn should_not_claim_token_moved_by_force_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_pending_transfer_mode(true)
+        .with_force_transfer_mode(ForceTransferMode::Enabled)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let reassigned_owner_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_3),
+    ));
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_owner,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => Key::Account(token_receiver),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TARGET_KEY => reassigned_owner_key,
+        },
+    )
+    .build();
+
+    builder
+        .exec(force_transfer_request)
+        .expect_success()
+        .commit();
+
+    // The force transfer drops the pending transfer, which can no longer undo the reassignment.
+    let pending_transfer: Option<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, PENDING_TRANSFERS, "0");
+    assert_eq!(pending_transfer, None);
+
+    let claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_receiver,
+        nft_contract_hash,
+        ENTRY_POINT_CLAIM,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(claim_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::MissingPendingTransfer as u16,
+        "should not claim a token moved by a force transfer",
+    );
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, "0");
+    assert_eq!(actual_token_owner, reassigned_owner_key);
+}
+
+#[test]
+fn should_approve_several_spenders_and_revoke_them_one_at_a_time() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let first_spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let second_spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let third_spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_3));
+    let token_id = 0u64;
+
+    for spender in [first_spender, second_spender, third_spender] {
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_APPROVE,
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_SPENDER => Key::Account(spender)
+            },
+        )
+        .build();
+        builder.exec(approve_request).expect_success().commit();
+    }
+
+    let approved_spenders: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(
+        approved_spenders,
+        vec![
+            Key::Account(first_spender),
+            Key::Account(second_spender),
+            Key::Account(third_spender)
+        ]
+    );
+
+    // Revoking a single spender leaves the others approved.
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SPENDER => Key::Account(first_spender)
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    let approved_spenders: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(
+        approved_spenders,
+        vec![Key::Account(second_spender), Key::Account(third_spender)]
+    );
+
+    let expected_event = SpenderRevoked::new(
+        owner_key,
+        Key::Account(first_spender),
+        TokenIdentifier::Index(token_id),
+    );
+    let actual_event: SpenderRevoked = support::get_event(&builder, &nft_contract_key, 4).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected SpenderRevoked event."
+    );
+
+    // get_approved reports the first remaining spender, get_approved_spenders all of them.
+    let actual_approved: Option<Key> = support::call_session_code_with_ret(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => token_id,
+        },
+        GET_APPROVED_WASM,
+        ARG_KEY_NAME,
+    );
+    assert_eq!(actual_approved, Some(Key::Account(second_spender)));
+
+    let actual_approved_spenders: Vec<Key> = support::call_session_code_with_ret(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => token_id,
+        },
+        GET_APPROVED_SPENDERS_WASM,
+        "approved_spenders",
+    );
+    assert_eq!(
+        actual_approved_spenders,
+        vec![Key::Account(second_spender), Key::Account(third_spender)]
+    );
+
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SPENDER => Key::Account(first_spender)
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::UnapprovedSpender as u16,
+        "should not revoke a spender that is not approved",
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        first_spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => owner_key,
+            ARG_TARGET_KEY => Key::Account(first_spender),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "a revoked spender should not transfer the token",
+    );
+
+    // Any remaining spender can transfer, which clears every approval.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        third_spender,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => owner_key,
+            ARG_TARGET_KEY => Key::Account(third_spender),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let approved_spenders: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(approved_spenders, Vec::<Key>::new());
+}
+
+#[test]
+fn should_approve_and_call_spender_contract_in_a_single_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let minting_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINTING_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(minting_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let minting_contract_key: Key = get_minting_contract_hash(&builder).into();
+    let minting_contract_package_key: Key = get_minting_contract_package_hash(&builder).into();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_id = 0u64;
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    // Only contracts can be called once approved.
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let approve_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE_AND_CALL,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SPENDER => Key::Account(spender),
+            ARG_ENTRY_POINT => "list".to_string(),
+        },
+    )
+    .build();
+    builder.exec(approve_and_call_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidApproveAndCallSpender as u16,
+        "should not approve and call an account",
+    );
+
+    // The listing contract package is approved, and its current version moves the token in escrow
+    // without the package operator mode.
+    let approve_and_call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE_AND_CALL,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SPENDER => minting_contract_package_key,
+            ARG_ENTRY_POINT => "list".to_string(),
+            ARG_DATA => Some(Bytes::from(vec![1u8, 2, 3])),
+        },
+    )
+    .build();
+    builder
+        .exec(approve_and_call_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(actual_token_owner, minting_contract_key);
+
+    let approved_spenders: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(approved_spenders, Vec::<Key>::new());
+}
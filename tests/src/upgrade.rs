@@ -3,18 +3,25 @@ use casper_engine_test_support::{
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 
-use casper_types::{account::AccountHash, runtime_args, CLValue, ContractHash, Key, RuntimeArgs};
+use casper_event_standard::Schemas;
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, runtime_args, CLValue, ContractHash, Key, RuntimeArgs,
+};
 use contract::{
     constants::{
         ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE,
         ARG_COLLECTION_NAME, ARG_EVENTS_MODE, ARG_HASH_KEY_NAME_1_0_0, ARG_NAMED_KEY_CONVENTION,
         ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_SOURCE_KEY, ARG_TARGET_KEY,
-        ARG_TOKEN_HASH, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY,
+        ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY,
         ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER, NUMBER_OF_MINTED_TOKENS, OPERATOR_BURN_MODE,
         PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PREFIX_ACCESS_KEY_NAME, PREFIX_HASH_KEY_NAME,
-        RECEIPT_NAME, UNMATCHED_HASH_COUNT,
+        PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, UNMATCHED_HASH_COUNT,
+    },
+    events::events_ces::{
+        Approval, ApprovalForAll, ApprovalForTokens, ApprovalRevoked, Burn, ForceTransfer,
+        MetadataUpdated, Migration, Mint, PendingTransfer, Revoked, SpenderRevoked, Transfer,
+        TransferFilterContractUpdated, TransferFilterContractsUpdated, VariablesSet,
     },
-    events::events_ces::Migration,
     modalities::EventsMode,
 };
 
@@ -440,14 +447,153 @@ fn should_update_receipts_post_upgrade_paged() {
         .expect("must have page 0 receipt");
 
     let actual_page_0 =
-        support::get_stored_value_from_global_state::<Vec<bool>>(&builder, receipt_page_0, vec![])
+        support::get_stored_value_from_global_state::<Bytes>(&builder, receipt_page_0, vec![])
             .expect("must get actual page");
 
-    for bit in actual_page_0.iter().take(number_of_tokens_pre_migration) {
+    for bit in support::unpack_bits(&actual_page_0)
+        .iter()
+        .take(number_of_tokens_pre_migration)
+    {
         assert!(*bit)
     }
 }
 
+#[test]
+fn should_repack_pages_when_upgrading_from_1_5_0() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, CONTRACT_1_5_0_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(2 * PAGE_SIZE)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash: ContractHash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // The second owner registers but holds no token at the time of the upgrade.
+    for token_owner in [*DEFAULT_ACCOUNT_ADDR, AccountHash::new(ACCOUNT_USER_1)] {
+        let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_REGISTER_OWNER,
+            runtime_args! {
+                ARG_TOKEN_OWNER => Key::Account(token_owner),
+            },
+        )
+        .build();
+
+        builder.exec(register_request).expect_success().commit();
+    }
+
+    // Spread the tokens over two pages.
+    let number_of_tokens_pre_migration = PAGE_SIZE + 1;
+
+    for _ in 0..number_of_tokens_pre_migration {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => "",
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    let get_page_keys = |builder: &InMemoryWasmTestBuilder| -> Vec<Key> {
+        let nft_contract_hash = support::get_nft_contract_hash(builder);
+        let nft_contract = builder
+            .get_contract(nft_contract_hash)
+            .expect("should have nft contract");
+        (0..2)
+            .map(|page_number| {
+                *nft_contract
+                    .named_keys()
+                    .get(&format!("{PREFIX_PAGE_DICTIONARY}_{page_number}"))
+                    .expect("must have page")
+            })
+            .collect()
+    };
+
+    let page_keys_pre_migration = get_page_keys(&builder);
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    // Pages are repacked within their dictionaries, leaving the receipts of owners valid.
+    assert_eq!(get_page_keys(&builder), page_keys_pre_migration);
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    // The transfer repacks the pages of both owners, and the registration of the owner holding no
+    // token carries over the upgrade.
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_ID => PAGE_SIZE,
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_SOURCE_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TARGET_KEY => Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let first_page = support::get_token_page_by_id(
+        &builder,
+        &nft_contract_key,
+        &Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        0u64,
+    );
+    assert_eq!(first_page.len() as u64, PAGE_SIZE);
+    assert!(first_page.into_iter().all(|is_owned| is_owned));
+
+    let second_page = support::get_token_page_by_id(
+        &builder,
+        &nft_contract_key,
+        &Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        PAGE_SIZE,
+    );
+    assert_eq!(second_page.len() as u64, PAGE_SIZE);
+    assert!(second_page.into_iter().all(|is_owned| !is_owned));
+
+    let token_receiver_page = support::get_token_page_by_id(
+        &builder,
+        &nft_contract_key,
+        &Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+        PAGE_SIZE,
+    );
+    assert!(token_receiver_page[0]);
+}
+
 #[test]
 fn should_not_be_able_to_reinvoke_migrate_entrypoint() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -1567,6 +1713,74 @@ fn should_safely_upgrade_from_1_5_0_to_current_version_without_supplying_events_
     assert_eq!(actual_event, expected_event, "Expected Migration event.");
 }
 
+#[test]
+fn should_register_new_event_schemas_when_upgrading_from_1_5_0_with_ces() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, CONTRACT_1_5_0_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .with_events_mode(EventsMode::CES)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash_1_5_0: ContractHash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key_1_5_0: Key = nft_contract_hash_1_5_0.into();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_EVENTS_MODE => EventsMode::CES as u8,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // Expects the schemas of the events added since 1.5.0 to be registered.
+    let expected_schemas = Schemas::new()
+        .with::<Mint>()
+        .with::<Burn>()
+        .with::<Revoked>()
+        .with::<Approval>()
+        .with::<ApprovalRevoked>()
+        .with::<SpenderRevoked>()
+        .with::<ApprovalForAll>()
+        .with::<ApprovalForTokens>()
+        .with::<Transfer>()
+        .with::<ForceTransfer>()
+        .with::<PendingTransfer>()
+        .with::<MetadataUpdated>()
+        .with::<VariablesSet>()
+        .with::<TransferFilterContractUpdated>()
+        .with::<TransferFilterContractsUpdated>()
+        .with::<Migration>();
+    let actual_schemas: Schemas = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![casper_event_standard::EVENTS_SCHEMA.to_string()],
+    );
+    assert_eq!(actual_schemas, expected_schemas, "Schemas mismatch.");
+}
+
 #[test]
 fn should_safely_upgrade_from_1_5_0_and_disable_events_mode() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -4,13 +4,17 @@ use casper_types::{
     account::AccountHash, bytesrepr::Bytes, CLValue, ContractHash, Key, RuntimeArgs,
 };
 use contract::constants::{
-    ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST, ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOW_MINTING,
+    ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST, ARG_ADDITIONAL_REQUIRED_METADATA,
+    ARG_ALLOWED_TOKEN_URI_SCHEMES, ARG_ALLOW_MINTING, ARG_BLOCKLIST, ARG_BURN_FILTER_CONTRACT,
     ARG_BURN_MODE, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST,
-    ARG_EVENTS_MODE, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA,
-    ARG_METADATA_MUTABILITY, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
-    ARG_NFT_METADATA_KIND, ARG_OPERATOR_BURN_MODE, ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE,
-    ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_TOTAL_TOKEN_SUPPLY,
-    ARG_TRANSFER_FILTER_CONTRACT, ARG_WHITELIST_MODE,
+    ARG_EVENTS_MODE, ARG_FORCE_TRANSFER_MODE, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE,
+    ARG_JSON_SCHEMA, ARG_METADATA_MUTABILITY, ARG_METADATA_SIZE_LIMITS, ARG_MINTING_MODE,
+    ARG_MINT_FILTER_CONTRACT, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND, ARG_NFT_METADATA_KIND,
+    ARG_OPEN_SUPPLY, ARG_OPERATOR_ALLOWLIST, ARG_OPERATOR_ALLOWLIST_MODE, ARG_OPERATOR_BURN_MODE,
+    ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_HOLDER_MODE,
+    ARG_PACKAGE_OPERATOR_MODE, ARG_PAGE_SIZE, ARG_PENDING_TRANSFER_MODE, ARG_TOTAL_TOKEN_SUPPLY,
+    ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_CONTRACTS, ARG_TRANSFER_FILTER_INTERFACE,
+    ARG_WHITELIST_MODE,
 };
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -18,7 +22,8 @@ use std::collections::BTreeMap;
 
 // Modalities reexports.
 pub use contract::modalities::{
-    EventsMode, MintingMode, NFTHolderMode, NFTKind, OwnershipMode, TokenIdentifier, WhitelistMode,
+    EventsMode, ForceTransferMode, MintingMode, NFTHolderMode, NFTKind, OperatorAllowlistMode,
+    OwnershipMode, TokenIdentifier, WhitelistMode,
 };
 
 use super::constants::{NFT_TEST_COLLECTION, NFT_TEST_SYMBOL};
@@ -137,6 +142,8 @@ pub(crate) struct InstallerRequestBuilder {
     acl_whitelist: CLValue,
     acl_package_mode: CLValue,
     package_operator_mode: CLValue,
+    package_holder_mode: CLValue,
+    pending_transfer_mode: CLValue,
     json_schema: CLValue,
     nft_metadata_kind: CLValue,
     identifier_mode: CLValue,
@@ -149,6 +156,18 @@ pub(crate) struct InstallerRequestBuilder {
     optional_metadata: CLValue,
     events_mode: CLValue,
     transfer_filter_contract: Option<CLValue>,
+    transfer_filter_interface: Option<CLValue>,
+    transfer_filter_contracts: Option<CLValue>,
+    mint_filter_contract: Option<CLValue>,
+    burn_filter_contract: Option<CLValue>,
+    allowed_token_uri_schemes: Option<CLValue>,
+    metadata_size_limits: Option<CLValue>,
+    open_supply: Option<CLValue>,
+    page_size: Option<CLValue>,
+    operator_allowlist_mode: Option<CLValue>,
+    operator_allowlist: Option<CLValue>,
+    force_transfer_mode: Option<CLValue>,
+    blocklist: Option<CLValue>,
 }
 
 impl InstallerRequestBuilder {
@@ -177,6 +196,8 @@ impl InstallerRequestBuilder {
             acl_whitelist: CLValue::from_t(Vec::<Key>::new()).unwrap(),
             acl_package_mode: CLValue::from_t(false).unwrap(),
             package_operator_mode: CLValue::from_t(false).unwrap(),
+            package_holder_mode: CLValue::from_t(false).unwrap(),
+            pending_transfer_mode: CLValue::from_t(false).unwrap(),
             json_schema: CLValue::from_t("test".to_string())
                 .expect("test_metadata was created from a concrete value"),
             nft_metadata_kind: CLValue::from_t(NFTMetadataKind::NFT721 as u8).unwrap(),
@@ -193,6 +214,18 @@ impl InstallerRequestBuilder {
             optional_metadata: CLValue::from_t(Bytes::new()).unwrap(),
             events_mode: CLValue::from_t(EventsMode::CES as u8).unwrap(),
             transfer_filter_contract: None,
+            transfer_filter_interface: None,
+            transfer_filter_contracts: None,
+            mint_filter_contract: None,
+            burn_filter_contract: None,
+            allowed_token_uri_schemes: None,
+            metadata_size_limits: None,
+            open_supply: None,
+            page_size: None,
+            operator_allowlist_mode: None,
+            operator_allowlist: None,
+            force_transfer_mode: None,
+            blocklist: None,
         }
     }
 
@@ -289,6 +322,18 @@ impl InstallerRequestBuilder {
         self
     }
 
+    pub(crate) fn with_package_holder_mode(mut self, package_holder_mode: bool) -> Self {
+        self.package_holder_mode =
+            CLValue::from_t(package_holder_mode).expect("package holder mode is legit CLValue");
+        self
+    }
+
+    pub(crate) fn with_pending_transfer_mode(mut self, pending_transfer_mode: bool) -> Self {
+        self.pending_transfer_mode =
+            CLValue::from_t(pending_transfer_mode).expect("pending transfer mode is legit CLValue");
+        self
+    }
+
     pub(crate) fn with_nft_metadata_kind(mut self, nft_metadata_kind: NFTMetadataKind) -> Self {
         self.nft_metadata_kind = CLValue::from_t(nft_metadata_kind as u8).unwrap();
         self
@@ -351,6 +396,86 @@ impl InstallerRequestBuilder {
         self
     }
 
+    pub(crate) fn with_transfer_filter_interface(mut self, transfer_filter_interface: u8) -> Self {
+        self.transfer_filter_interface = Some(CLValue::from_t(transfer_filter_interface).unwrap());
+        self
+    }
+
+    pub(crate) fn with_transfer_filter_contracts(
+        mut self,
+        transfer_filter_contracts: Vec<Key>,
+    ) -> Self {
+        self.transfer_filter_contracts = Some(CLValue::from_t(transfer_filter_contracts).unwrap());
+        self
+    }
+
+    pub(crate) fn with_mint_filter_contract(mut self, mint_filter_contract: Key) -> Self {
+        self.mint_filter_contract = Some(CLValue::from_t(mint_filter_contract).unwrap());
+        self
+    }
+
+    pub(crate) fn with_burn_filter_contract(mut self, burn_filter_contract: Key) -> Self {
+        self.burn_filter_contract = Some(CLValue::from_t(burn_filter_contract).unwrap());
+        self
+    }
+
+    pub(crate) fn with_allowed_token_uri_schemes(
+        mut self,
+        allowed_token_uri_schemes: Vec<String>,
+    ) -> Self {
+        self.allowed_token_uri_schemes = Some(CLValue::from_t(allowed_token_uri_schemes).unwrap());
+        self
+    }
+
+    pub(crate) fn with_metadata_size_limits(
+        mut self,
+        metadata_size_limits: Vec<(NFTMetadataKind, u32)>,
+    ) -> Self {
+        let metadata_size_limits: BTreeMap<u8, u32> = metadata_size_limits
+            .into_iter()
+            .map(|(metadata_kind, size_limit)| (metadata_kind as u8, size_limit))
+            .collect();
+        self.metadata_size_limits = Some(CLValue::from_t(metadata_size_limits).unwrap());
+        self
+    }
+
+    pub(crate) fn with_open_supply(mut self, open_supply: bool) -> Self {
+        self.open_supply = Some(CLValue::from_t(open_supply).unwrap());
+        self
+    }
+
+    pub(crate) fn with_page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(CLValue::from_t(page_size).unwrap());
+        self
+    }
+
+    pub(crate) fn with_operator_allowlist_mode(
+        mut self,
+        operator_allowlist_mode: OperatorAllowlistMode,
+    ) -> Self {
+        self.operator_allowlist_mode =
+            Some(CLValue::from_t(operator_allowlist_mode as u8).unwrap());
+        self
+    }
+
+    pub(crate) fn with_operator_allowlist(mut self, operator_allowlist: Vec<Key>) -> Self {
+        self.operator_allowlist = Some(CLValue::from_t(operator_allowlist).unwrap());
+        self
+    }
+
+    pub(crate) fn with_force_transfer_mode(
+        mut self,
+        force_transfer_mode: ForceTransferMode,
+    ) -> Self {
+        self.force_transfer_mode = Some(CLValue::from_t(force_transfer_mode as u8).unwrap());
+        self
+    }
+
+    pub(crate) fn with_blocklist(mut self, blocklist: Vec<Key>) -> Self {
+        self.blocklist = Some(CLValue::from_t(blocklist).unwrap());
+        self
+    }
+
     pub(crate) fn build(self) -> ExecuteRequest {
         let mut runtime_args = RuntimeArgs::new();
         runtime_args.insert_cl_value(ARG_COLLECTION_NAME, self.collection_name);
@@ -359,6 +484,8 @@ impl InstallerRequestBuilder {
         runtime_args.insert_cl_value(ARG_ALLOW_MINTING, self.allow_minting);
         runtime_args.insert_cl_value(ARG_ACL_PACKAGE_MODE, self.acl_package_mode);
         runtime_args.insert_cl_value(ARG_PACKAGE_OPERATOR_MODE, self.package_operator_mode);
+        runtime_args.insert_cl_value(ARG_PACKAGE_HOLDER_MODE, self.package_holder_mode);
+        runtime_args.insert_cl_value(ARG_PENDING_TRANSFER_MODE, self.pending_transfer_mode);
         runtime_args.insert_cl_value(ARG_MINTING_MODE, self.minting_mode.clone());
         runtime_args.insert_cl_value(ARG_OWNERSHIP_MODE, self.ownership_mode);
         runtime_args.insert_cl_value(ARG_NFT_KIND, self.nft_kind);
@@ -391,6 +518,42 @@ impl InstallerRequestBuilder {
         if let Some(transfer_filter_contract) = self.transfer_filter_contract {
             runtime_args.insert_cl_value(ARG_TRANSFER_FILTER_CONTRACT, transfer_filter_contract);
         }
+        if let Some(transfer_filter_interface) = self.transfer_filter_interface {
+            runtime_args.insert_cl_value(ARG_TRANSFER_FILTER_INTERFACE, transfer_filter_interface);
+        }
+        if let Some(transfer_filter_contracts) = self.transfer_filter_contracts {
+            runtime_args.insert_cl_value(ARG_TRANSFER_FILTER_CONTRACTS, transfer_filter_contracts);
+        }
+        if let Some(mint_filter_contract) = self.mint_filter_contract {
+            runtime_args.insert_cl_value(ARG_MINT_FILTER_CONTRACT, mint_filter_contract);
+        }
+        if let Some(burn_filter_contract) = self.burn_filter_contract {
+            runtime_args.insert_cl_value(ARG_BURN_FILTER_CONTRACT, burn_filter_contract);
+        }
+        if let Some(allowed_token_uri_schemes) = self.allowed_token_uri_schemes {
+            runtime_args.insert_cl_value(ARG_ALLOWED_TOKEN_URI_SCHEMES, allowed_token_uri_schemes);
+        }
+        if let Some(metadata_size_limits) = self.metadata_size_limits {
+            runtime_args.insert_cl_value(ARG_METADATA_SIZE_LIMITS, metadata_size_limits);
+        }
+        if let Some(open_supply) = self.open_supply {
+            runtime_args.insert_cl_value(ARG_OPEN_SUPPLY, open_supply);
+        }
+        if let Some(page_size) = self.page_size {
+            runtime_args.insert_cl_value(ARG_PAGE_SIZE, page_size);
+        }
+        if let Some(operator_allowlist_mode) = self.operator_allowlist_mode {
+            runtime_args.insert_cl_value(ARG_OPERATOR_ALLOWLIST_MODE, operator_allowlist_mode);
+        }
+        if let Some(operator_allowlist) = self.operator_allowlist {
+            runtime_args.insert_cl_value(ARG_OPERATOR_ALLOWLIST, operator_allowlist);
+        }
+        if let Some(force_transfer_mode) = self.force_transfer_mode {
+            runtime_args.insert_cl_value(ARG_FORCE_TRANSFER_MODE, force_transfer_mode);
+        }
+        if let Some(blocklist) = self.blocklist {
+            runtime_args.insert_cl_value(ARG_BLOCKLIST, blocklist);
+        }
         ExecuteRequestBuilder::standard(self.account_hash, &self.session_file, runtime_args).build()
     }
 }
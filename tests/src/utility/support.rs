@@ -2,8 +2,8 @@ use super::{
     constants::MINTING_CONTRACT_PACKAGE_NAME, installer_request_builder::InstallerRequestBuilder,
 };
 use crate::utility::constants::{
-    ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH, CONTRACT_NAME, MINTING_CONTRACT_NAME, PAGE_SIZE,
-    TRANSFER_FILTER_CONTRACT_NAME,
+    ARG_FILTER_CONTRACT_RETURN_VALUE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH, CONTRACT_NAME,
+    MINTING_CONTRACT_NAME, PAGE_SIZE, TRANSFER_FILTER_CONTRACT_NAME, TRANSFER_FILTER_CONTRACT_WASM,
 };
 use blake2::{
     digest::{Update, VariableOutput},
@@ -25,12 +25,52 @@ use casper_types::{
     ApiError, CLTyped, CLValueError, ContractHash, ContractPackageHash, Key, PublicKey,
     RuntimeArgs, SecretKey, URef, BLAKE2B_DIGEST_LENGTH,
 };
-use contract::constants::{HASH_KEY_NAME_1_0_0, INDEX_BY_HASH, PREFIX_PAGE_DICTIONARY};
+use contract::{
+    constants::{HASH_KEY_NAME_1_0_0, INDEX_BY_HASH, PREFIX_PAGE_DICTIONARY},
+    modalities::TransferFilterContractResult,
+};
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use sha256::digest;
 use std::fmt::Debug;
 
+pub(crate) fn install_transfer_filter_contract(
+    builder: &mut InMemoryWasmTestBuilder,
+    return_value: TransferFilterContractResult,
+) -> ContractHash {
+    let transfer_filter_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_FILTER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(transfer_filter_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let transfer_filter_contract_hash = get_transfer_filter_contract_hash(builder);
+
+    let transfer_filter_contract_set_return_value_request =
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            transfer_filter_contract_hash,
+            "set_return_value",
+            runtime_args! {
+                ARG_FILTER_CONTRACT_RETURN_VALUE => return_value as u8
+            },
+        )
+        .build();
+
+    builder
+        .exec(transfer_filter_contract_set_return_value_request)
+        .expect_success()
+        .commit();
+
+    transfer_filter_contract_hash
+}
+
 pub(crate) fn get_nft_contract_hash(
     builder: &WasmTestBuilder<InMemoryGlobalState>,
 ) -> ContractHash {
@@ -128,7 +168,7 @@ pub(crate) fn get_dictionary_value_from_key<T: CLTyped + FromBytes>(
         .unwrap()
 }
 
-fn create_dummy_key_pair(account_string: [u8; 32]) -> (SecretKey, PublicKey) {
+pub(crate) fn create_dummy_key_pair(account_string: [u8; 32]) -> (SecretKey, PublicKey) {
     let secret_key =
         SecretKey::ed25519_from_bytes(account_string).expect("failed to create secret key");
     let public_key = PublicKey::from(&secret_key);
@@ -287,12 +327,21 @@ pub(crate) fn get_token_page_by_id(
 ) -> Vec<bool> {
     let page_number = token_id / PAGE_SIZE;
     let token_page_item_key = make_page_dictionary_item_key(token_owner_key);
-    get_dictionary_value_from_key(
+    let token_page: Bytes = get_dictionary_value_from_key(
         builder,
         nft_contract_key,
         &format!("{PREFIX_PAGE_DICTIONARY}_{page_number}"),
         &token_page_item_key,
-    )
+    );
+    unpack_bits(&token_page)
+}
+
+// Pages and page tables are stored as bitsets, eight slots to a byte.
+pub(crate) fn unpack_bits(bitset: &[u8]) -> Vec<bool> {
+    bitset
+        .iter()
+        .flat_map(|byte| (0..8).map(move |bit| byte & (1 << bit) != 0))
+        .collect()
 }
 
 pub(crate) fn get_token_page_by_hash(